@@ -1,5 +1,67 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Config {
+  pub owner: Pubkey,
+  pub pending_owner: Pubkey,  // set by propose_owner, cleared on accept or cancel
+  pub owner_vault: Pubkey,
+  pub user: Pubkey,
+  pub multisig: Pubkey,       // once set, treasury, admin, config and ownership changes need a passed proposal
+  pub campaign_vault: Pubkey, // holds escrowed campaign fees and signs their release
+  pub news_payout: u64,   // credited to the reporter vault on publish
+  pub campaign_fee: u64,  // escrowed when a campaign is created
+  pub approval_quorum: u32,   // distinct senior approvals an article needs
+  pub deny_policy: u32,       // DENY_BLOCKS_POLICY or DENY_RESETS_POLICY
+  pub max_resubmissions: u32, // resubmit_news calls allowed per article
+  pub review_deadline: u32    // seconds from updated_at before a review can be escalated, 0 to disable
+}
+
+impl Default for Config {
+  #[inline]
+  fn default() -> Config {
+      Config {
+          owner: Pubkey::default(),
+          pending_owner: Pubkey::default(),
+          owner_vault: Pubkey::default(),
+          user: Pubkey::default(),
+          multisig: Pubkey::default(),
+          campaign_vault: Pubkey::default(),
+          news_payout: 0,
+          campaign_fee: 0,
+          approval_quorum: 1,
+          deny_policy: DENY_BLOCKS_POLICY,
+          max_resubmissions: DEFAULT_MAX_RESUBMISSIONS,
+          review_deadline: 0
+      }
+  }
+}
+
+impl Config {
+  pub fn set_review_policy(
+    &mut self, 
+    approval_quorum: u32, 
+    deny_policy: u32, 
+    max_resubmissions: u32, 
+    review_deadline: u32
+  ) -> Result<()> {
+    require!(
+      approval_quorum > 0 && 
+      (deny_policy == DENY_BLOCKS_POLICY || deny_policy == DENY_RESETS_POLICY), 
+      NewsError::InvalidReviewPolicy
+    );
+
+    self.approval_quorum = approval_quorum;
+    self.deny_policy = deny_policy;
+    self.max_resubmissions = max_resubmissions;
+    self.review_deadline = review_deadline;
+
+    Ok(())
+  }
+}
 
 #[account(zero_copy)]
 // #[repr(packed)]
@@ -8,7 +70,33 @@ pub struct Pool {
   pub reporter: Pubkey,
   pub created_at: u32,
   pub updated_at: u32,
-  pub state: u32
+  pub state: u32,  // NewsState
+  pub content_hash: [u8; 32],   // e.g. SHA-256 of the article body
+  pub approved_hash: [u8; 32],  // content_hash at approval, cleared by any later edit
+  pub uri_len: u32,
+  pub revision: u32,            // number of the latest Revision record
+  pub approved_revision: u32,   // 0 while no revision is approved
+  pub published_revision: u32,
+  pub published_at: u32,
+  pub approval_count: u32,      // approvals collected in the current round
+  pub approval_round: u32,      // bumped whenever collected approvals are discarded
+  pub deny_reason: u32,         // reason code of the latest deny
+  pub resubmit_count: u32,
+  pub feedback_hash: [u8; 32],  // hash of the reviewer feedback behind the latest deny
+  pub publish_at: u32,          // 0 while no publication is scheduled
+  pub retract_reason: u32,
+  pub retracted_at: u32,
+  pub correction_count: u32,
+  pub payout: u64,              // credited to the reporter vault on publish
+  pub category: Pubkey,         // the Category this article is filed under
+  pub tag_count: u32,
+  pub tags: [u32; MAX_TAG_COUNT],
+  pub coauthor_count: u32,      // 0 for a single-author article credited to `reporter`
+  pub coauthors: [CoAuthor; MAX_COAUTHOR_COUNT],
+  pub sequence: u64,            // canonical article id assigned by the NewsRegistry
+  pub escalated_at: u32,        // set by escalate_review to open the article to admins, cleared when the content changes
+  pub review_due_at: u32,       // the deadline that was missed, recorded with escalated_at
+  pub uri: [u8; MAX_URI_LENGTH] // storage location such as an Arweave or IPFS id
 }
 
 impl Default for Pool {
@@ -16,14 +104,451 @@ impl Default for Pool {
   fn default() -> Pool {
       Pool {
           news_id: 0,
-          reporter: anchor_lang::solana_program::pubkey!("hFPVCyAan8HVuC3AVM34usCqUcEnqmDjdkHPk5NrAKW"),
+          reporter: Pubkey::default(),
           created_at: 0,
           updated_at: 0,
-          state: 0
+          state: 0,
+          content_hash: [0; 32],
+          approved_hash: [0; 32],
+          uri_len: 0,
+          revision: 0,
+          approved_revision: 0,
+          published_revision: 0,
+          published_at: 0,
+          approval_count: 0,
+          approval_round: 0,
+          deny_reason: 0,
+          resubmit_count: 0,
+          feedback_hash: [0; 32],
+          publish_at: 0,
+          retract_reason: 0,
+          retracted_at: 0,
+          correction_count: 0,
+          payout: 0,
+          category: Pubkey::default(),
+          tag_count: 0,
+          tags: [0; MAX_TAG_COUNT],
+          coauthor_count: 0,
+          coauthors: [
+            CoAuthor {
+                    ..Default::default()
+                }; MAX_COAUTHOR_COUNT
+          ],
+          sequence: 0,
+          escalated_at: 0,
+          review_due_at: 0,
+          uri: [0; MAX_URI_LENGTH]
       }
   }
 }
 
+impl Pool {
+  pub fn news_state(&self) -> Result<NewsState> {
+    NewsState::try_from(self.state)
+  }
+
+  pub fn transition(&mut self, next: NewsState) -> Result<()> {
+    let current = self.news_state()?;
+    require!(
+      current.can_transition_to(next),
+      NewsError::InvalidStateTransition
+    );
+
+    self.state = next as u32;
+    Ok(())
+  }
+
+  pub fn set_uri(&mut self, uri: &str) -> Result<()> {
+    self.uri_len = write_uri(&mut self.uri, uri)?;
+
+    Ok(())
+  }
+
+  pub fn set_tags(&mut self, tags: &[u32]) -> Result<()> {
+    require!(tags.len() <= MAX_TAG_COUNT, NewsError::TooManyTags);
+
+    self.tags = [0; MAX_TAG_COUNT];
+    self.tags[..tags.len()].copy_from_slice(tags);
+    self.tag_count = tags.len() as u32;
+
+    Ok(())
+  }
+
+  // Reviewers and publishers must not act on an article they are credited on.
+  pub fn validate_reviewer(&self, reviewer: Pubkey) -> Result<()> {
+    require!(self.reporter != reviewer, NewsError::ConflictOfInterest);
+    require!(self.find_coauthor(reviewer).is_none(), NewsError::ConflictOfInterest);
+
+    Ok(())
+  }
+
+  pub fn find_coauthor(&self, author: Pubkey) -> Option<usize> {
+    self.coauthors[..self.coauthor_count as usize]
+      .iter()
+      .position(|item| item.author == author)
+  }
+
+  // Every listed author other than the reporter has to accept again after the list changes.
+  pub fn set_coauthors(&mut self, authors: &[Pubkey], shares: &[u32]) -> Result<()> {
+    require!(
+      authors.len() == shares.len() && authors.len() <= MAX_COAUTHOR_COUNT,
+      NewsError::InvalidCoAuthors
+    );
+    for (i, author) in authors.iter().enumerate() {
+      require!(!authors[..i].contains(author), NewsError::InvalidCoAuthors);
+    }
+    if !authors.is_empty() {
+      require!(
+        shares.iter().all(|share| *share > 0) &&
+        shares.iter().map(|share| *share as u64).sum::<u64>() == TOTAL_SHARE_BPS as u64,
+        NewsError::InvalidShares
+      );
+    }
+
+    self.coauthors = [CoAuthor::default(); MAX_COAUTHOR_COUNT];
+    for (i, (author, share)) in authors.iter().zip(shares).enumerate() {
+      self.coauthors[i] = CoAuthor {
+        author: *author,
+        share_bps: *share,
+        accepted: (*author == self.reporter) as u32,
+      };
+    }
+    self.coauthor_count = authors.len() as u32;
+
+    Ok(())
+  }
+
+  pub fn accept_coauthorship(&mut self, author: Pubkey) -> Result<()> {
+    let index = match self.find_coauthor(author) {
+      Some(index) => index,
+      None => return err!(NewsError::NotCoAuthor)
+    };
+
+    self.coauthors[index].accepted = 1;
+
+    Ok(())
+  }
+
+  // The amount owed to each author's vault, with rounding dust going to the last author.
+  pub fn payout_shares(&self, payout: u64) -> Vec<(Pubkey, u64)> {
+    if self.coauthor_count == 0 {
+      return vec![(self.reporter, payout)];
+    }
+
+    let coauthors = &self.coauthors[..self.coauthor_count as usize];
+    let mut shares: Vec<(Pubkey, u64)> = coauthors
+      .iter()
+      // Shares sum to TOTAL_SHARE_BPS, so each result fits back into a u64.
+      .map(|item| (item.author, (payout as u128 * item.share_bps as u128 / TOTAL_SHARE_BPS as u128) as u64))
+      .collect();
+    let dust = payout - shares.iter().map(|(_, amount)| amount).sum::<u64>();
+    shares[coauthors.len() - 1].1 += dust;
+
+    shares
+  }
+
+  // Starting a new round makes every NewsApproval of the previous one unreachable,
+  // and a schedule set for the approved content no longer applies.
+  pub fn reset_approvals(&mut self) {
+    self.approved_hash = [0; 32];
+    self.approved_revision = 0;
+    self.approval_count = 0;
+    self.approval_round += 1;
+    self.publish_at = 0;
+  }
+
+  pub fn publish(&mut self, now: u32, payout: u64) -> Result<()> {
+    require!(
+      self.news_state()? == NewsState::Approved,
+      NewsError::NotApprovedNews
+    );
+    require!(
+      self.approved_hash == self.content_hash,
+      NewsError::ContentHashMismatch
+    );
+    require!(
+      self.coauthors[..self.coauthor_count as usize].iter().all(|item| item.accepted != 0),
+      NewsError::CoAuthorsNotAccepted
+    );
+
+    self.transition(NewsState::Published)?;
+    self.published_revision = self.approved_revision;
+    self.published_at = now;
+    self.publish_at = 0;
+    self.payout = payout;
+
+    Ok(())
+  }
+}
+
+#[zero_copy]
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct CoAuthor {
+  pub author: Pubkey,
+  pub share_bps: u32,     // out of TOTAL_SHARE_BPS
+  pub accepted: u32
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Category {
+  pub category_id: u32,
+  pub approval_quorum: u32,     // 0 to fall back to Config.approval_quorum
+  pub name_hash: [u8; 32],
+  pub created_by: Pubkey,
+  pub created_at: u32,
+  pub escalation_count: u32
+}
+
+impl Default for Category {
+  #[inline]
+  fn default() -> Category {
+      Category {
+          category_id: 0,
+          approval_quorum: 0,
+          name_hash: [0; 32],
+          created_by: Pubkey::default(),
+          created_at: 0,
+          escalation_count: 0
+      }
+  }
+}
+
+impl Category {
+  pub fn approval_quorum(&self, config: &Config) -> u32 {
+    match self.approval_quorum {
+      0 => config.approval_quorum,
+      quorum => quorum
+    }
+  }
+}
+
+// Grants one senior review rights over one category; revoking closes the account.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct CategoryEditor {
+  pub category: Pubkey,
+  pub editor: Pubkey,
+  pub granted_by: Pubkey,
+  pub granted_at: u32,
+  pub escalation_count: u32     // reviews in this category escalated while the grant was held
+}
+
+impl Default for CategoryEditor {
+  #[inline]
+  fn default() -> CategoryEditor {
+      CategoryEditor {
+          category: Pubkey::default(),
+          editor: Pubkey::default(),
+          granted_by: Pubkey::default(),
+          granted_at: 0,
+          escalation_count: 0
+      }
+  }
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct NewsRegistry {
+  pub count: u64,         // articles ever created, and the sequence of the next one
+  pub page_count: u64
+}
+
+impl Default for NewsRegistry {
+  #[inline]
+  fn default() -> NewsRegistry {
+      NewsRegistry {
+          count: 0,
+          page_count: 0
+      }
+  }
+}
+
+#[zero_copy]
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct IndexEntry {
+  pub pool: Pubkey,
+  pub state: u32          // NewsState, updated by every instruction that moves the pool, or DELETED_INDEX_STATE
+}
+
+// Page `n` maps sequences `n * INDEX_PAGE_SIZE ..` to their pools, in creation order.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct IndexPage {
+  pub page: u64,
+  pub count: u32,
+  pub entries: [IndexEntry; INDEX_PAGE_SIZE as usize]
+}
+
+impl Default for IndexPage {
+  #[inline]
+  fn default() -> IndexPage {
+    IndexPage {
+          page: 0,
+          count: 0,
+          entries: [
+            IndexEntry {
+                    ..Default::default()
+                }; INDEX_PAGE_SIZE as usize
+          ]
+      }
+  }
+}
+
+impl IndexPage {
+  pub fn push(&mut self, sequence: u64, pool: Pubkey, state: u32) {
+    let index = (sequence % INDEX_PAGE_SIZE) as usize;
+
+    self.entries[index] = IndexEntry { pool, state };
+    self.count = index as u32 + 1;
+  }
+
+  pub fn set_state(&mut self, sequence: u64, state: u32) {
+    self.entries[(sequence % INDEX_PAGE_SIZE) as usize].state = state;
+  }
+}
+
+// Attached to a published or retracted article, seeded by the pool and the correction number.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Correction {
+  pub pool: Pubkey,
+  pub issuer: Pubkey,
+  pub content_hash: [u8; 32],   // hash of the corrected content
+  pub index: u32,
+  pub created_at: u32
+}
+
+impl Default for Correction {
+  #[inline]
+  fn default() -> Correction {
+      Correction {
+          pool: Pubkey::default(),
+          issuer: Pubkey::default(),
+          content_hash: [0; 32],
+          index: 0,
+          created_at: 0
+      }
+  }
+}
+
+// One per editor grant and escalation, so count_editor_escalation counts each grant once.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct EditorEscalation {
+  pub pool: Pubkey,
+  pub category_editor: Pubkey,
+  pub escalated_at: u32,
+  pub counted_at: u32
+}
+
+impl Default for EditorEscalation {
+  #[inline]
+  fn default() -> EditorEscalation {
+      EditorEscalation {
+          pool: Pubkey::default(),
+          category_editor: Pubkey::default(),
+          escalated_at: 0,
+          counted_at: 0
+      }
+  }
+}
+
+// One per reviewer and approval round, so a senior cannot approve the same round twice.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct NewsApproval {
+  pub pool: Pubkey,
+  pub reviewer: Pubkey,
+  pub round: u32,
+  pub approved_at: u32
+}
+
+impl Default for NewsApproval {
+  #[inline]
+  fn default() -> NewsApproval {
+      NewsApproval {
+          pool: Pubkey::default(),
+          reviewer: Pubkey::default(),
+          round: 0,
+          approved_at: 0
+      }
+  }
+}
+
+// One per version of an article, seeded by the pool and the revision number.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Revision {
+  pub pool: Pubkey,
+  pub editor: Pubkey,
+  pub content_hash: [u8; 32],
+  pub revision: u32,
+  pub created_at: u32
+}
+
+impl Default for Revision {
+  #[inline]
+  fn default() -> Revision {
+      Revision {
+          pool: Pubkey::default(),
+          editor: Pubkey::default(),
+          content_hash: [0; 32],
+          revision: 0,
+          created_at: 0
+      }
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum NewsState {
+  Draft = 0,
+  Edited = 1,
+  Approved = 2,
+  Denied = 3,
+  Published = 4,
+  Retracted = 5,
+}
+
+impl NewsState {
+  // Every news instruction moves a pool along one of these edges and nothing else.
+  pub fn can_transition_to(self, next: NewsState) -> bool {
+    use NewsState::*;
+    matches!(
+      (self, next),
+      (Draft, Edited) | (Edited, Edited) | (Approved, Edited) | (Denied, Edited) |
+      (Draft, Approved) | (Edited, Approved) |
+      (Draft, Denied) | (Edited, Denied) |
+      (Approved, Published) |
+      (Published, Retracted)
+    )
+  }
+
+  // A published article has already been credited from the treasury, so its pool must stay,
+  // and a retracted one is kept as the record of the retraction.
+  pub fn is_closable(self) -> bool {
+    use NewsState::*;
+    matches!(self, Draft | Edited | Approved | Denied)
+  }
+}
+
+impl TryFrom<u32> for NewsState {
+  type Error = anchor_lang::error::Error;
+
+  fn try_from(state: u32) -> Result<NewsState> {
+    match state {
+      0 => Ok(NewsState::Draft),
+      1 => Ok(NewsState::Edited),
+      2 => Ok(NewsState::Approved),
+      3 => Ok(NewsState::Denied),
+      4 => Ok(NewsState::Published),
+      5 => Ok(NewsState::Retracted),
+      _ => err!(NewsError::InvalidNewsState),
+    }
+  }
+}
+
 #[account(zero_copy)]
 // #[repr(packed)]
 pub struct OwnerVault {
@@ -35,7 +560,7 @@ impl Default for OwnerVault {
   #[inline]
   fn default() -> OwnerVault {
      OwnerVault {
-          owner: anchor_lang::solana_program::pubkey!("3ttYrBAp5D2sTG2gaBjg8EtrZecqBQSBuFRhsqHWPYxX"),
+          owner: Pubkey::default(),
           balance: 0
       }
   }
@@ -52,7 +577,7 @@ impl Default for Vault {
   #[inline]
   fn default() -> Vault {
       Vault {
-          reporter: anchor_lang::solana_program::pubkey!("1294RwniF1cjKmsPLU1dHHaYXTCvrCQt8uY9tfuaCMPi"),
+          reporter: Pubkey::default(),
           balance: 0
       }
   }
@@ -79,77 +604,206 @@ impl Default for User {
   }
 }
 
+// `reporters[..count]` holds unique keys, every slot past `count` is empty, and each
+// mutation either updates `count` along with the slots or fails without touching either.
 impl User {
-  pub fn find_reporter(&self, reporter: Pubkey) -> usize {
-    let mut index = MAX_REPORTER_COUNT;
-    for i in 0..self.count as usize {
-      if self.reporters[i].reporter == reporter {
-        index = i;
-        break;
-      }
-    }
-
-    index
+  pub fn find_reporter(&self, reporter: Pubkey) -> Option<usize> {
+    self.reporters[..self.count as usize]
+      .iter()
+      .position(|item| item.reporter == reporter)
   }
 
-  pub fn validate_reporter(&self, reporter: Pubkey, role: u32) -> Result<bool> {
-    let mut result = true;
-    // for i in 0..self.count as usize {
-    //   if self.reporters[i].reporter.key() == reporter.key() && self.reporters[i].role == role {
-    //     result = true;
-    //     break;
-    //   }
-    // }
+  pub fn add_reporter(&mut self, reporter: Pubkey, role: u32) -> Result<()> {
+    require!(
+      self.find_reporter(reporter).is_none(),
+      NewsError::DuplicateReporter
+    );
+    require!(
+      (self.count as usize) < MAX_REPORTER_COUNT,
+      NewsError::OverMaxCount
+    );
 
-    msg!("validate result {}", result);
-    msg!("reporter {}", reporter.key());
-    msg!("role {}", role);
+    self.reporters[self.count as usize] = Reporter {
+      reporter,
+      role,
+    };
+    self.count += 1;
 
-    Ok(result)
+    Ok(())
   }
 
-  pub fn add_reporter(&mut self, reporter: Pubkey, role: u32) -> Result<bool> {
-    let index = self.find_reporter(reporter);
-    if index < MAX_REPORTER_COUNT {
-      return Ok(false);
+  pub fn edit_reporter(&mut self, old_reporter: Pubkey, reporter: Pubkey, role: u32) -> Result<()> {
+    let index = match self.find_reporter(old_reporter) {
+      Some(index) => index,
+      None => return err!(NewsError::ReporterNotFound)
+    };
+    if reporter != old_reporter {
+      require!(
+        self.find_reporter(reporter).is_none(),
+        NewsError::DuplicateReporter
+      );
     }
-    self.reporters[self.count as usize] = Reporter {
+
+    self.reporters[index] = Reporter {
       reporter,
       role,
     };
 
-    Ok(true)
+    Ok(())
   }
 
-  pub fn edit_reporter(&mut self, old_reporter: Pubkey, reporter: Pubkey, role: u32) -> Result<bool> {
-    let index = self.find_reporter(old_reporter);
+  pub fn delete_reporter(&mut self, reporter: Pubkey) -> Result<()> {
+    let index = match self.find_reporter(reporter) {
+      Some(index) => index,
+      None => return err!(NewsError::ReporterNotFound)
+    };
+    let last = self.count as usize - 1;
 
-    if index < MAX_REPORTER_COUNT {
-      msg!("edit_reporter");
-      msg!("role {}", role);
-      msg!("reporter {}", reporter);
-      self.reporters[index].reporter = reporter;
-      self.reporters[index].role = role;
-    } else {
-      msg!("add_reporter");
-      msg!("reporter {}", reporter);
-      msg!("role {}", role);
-      self.add_reporter(reporter, role);
-    }
+    self.reporters.copy_within(index + 1..=last, index);
+    self.reporters[last] = Reporter::default();
+    self.count -= 1;
 
-    Ok(true)
+    Ok(())
   }
+}
 
-  pub fn delete_reporter(&mut self, reporter: Pubkey) -> Result<bool> {
-    let index = self.find_reporter(reporter);
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Member {
+  pub registry: Pubkey,   // the User registry this membership belongs to
+  pub reporter: Pubkey,
+  pub permissions: u32,   // bitset of *_PERMISSION flags
+  pub status: u32,
+  pub joined_at: u32,
+  pub expires_at: u32,    // 0 for a permanent grant
+  pub status_reason: u32, // reason code of the last suspend / reinstate
+  pub status_updated_by: Pubkey,
+  pub status_updated_at: u32
+}
 
-    if index < MAX_REPORTER_COUNT {
-      for i in index..self.count as usize - 1 {
-        self.reporters[i] = self.reporters[i + 1];
+impl Default for Member {
+  #[inline]
+  fn default() -> Member {
+      Member {
+          registry: Pubkey::default(),
+          reporter: Pubkey::default(),
+          permissions: 0,
+          status: ACTIVE_STATUS,
+          joined_at: 0,
+          expires_at: 0,
+          status_reason: 0,
+          status_updated_by: Pubkey::default(),
+          status_updated_at: 0
       }
+  }
+}
+
+impl Member {
+  pub fn is_expired(&self, now: u32) -> bool {
+    self.expires_at != 0 && self.expires_at <= now
+  }
+
+  pub fn validate_active(&self) -> Result<()> {
+    require!(self.status != SUSPENDED_STATUS, NewsError::ReporterSuspended);
+
+    Ok(())
+  }
+
+  pub fn validate_permission(&self, permission: u32) -> Result<()> {
+    require!(!self.is_expired(get_current_time()?), NewsError::RoleExpired);
+    self.validate_active()?;
+
+    let result = self.status == ACTIVE_STATUS && self.permissions & permission == permission;
+
+    if !result {
+      return match permission {
+        SUBMIT_PERMISSION => err!(NewsError::NotJuniorReporter),
+        REVIEW_PERMISSION => err!(NewsError::NotSeniorReporter),
+        _ => err!(NewsError::MissingPermission),
+      };
     }
 
-    return Ok(true);
+    Ok(())
+  }
+}
+
+// Exists only while an application is pending; accepting or rejecting it closes the account.
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Application {
+  pub registry: Pubkey,
+  pub applicant: Pubkey,
+  pub applied_at: u32
+}
+
+impl Default for Application {
+  #[inline]
+  fn default() -> Application {
+      Application {
+          registry: Pubkey::default(),
+          applicant: Pubkey::default(),
+          applied_at: 0
+      }
+  }
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct ReporterProfile {
+  pub reporter: Pubkey,
+  pub name_hash: [u8; 32],
+  pub joined_at: u32,
+  pub submitted_count: u32,
+  pub approved_count: u32,
+  pub denied_count: u32,
+  pub published_count: u32,
+  pub uri_len: u32,
+  pub lifetime_earnings: u64,
+  pub uri: [u8; MAX_URI_LENGTH]
+}
+
+impl Default for ReporterProfile {
+  #[inline]
+  fn default() -> ReporterProfile {
+      ReporterProfile {
+          reporter: Pubkey::default(),
+          name_hash: [0; 32],
+          joined_at: 0,
+          submitted_count: 0,
+          approved_count: 0,
+          denied_count: 0,
+          published_count: 0,
+          uri_len: 0,
+          lifetime_earnings: 0,
+          uri: [0; MAX_URI_LENGTH]
+      }
+  }
+}
+
+impl ReporterProfile {
+  pub fn set_uri(&mut self, uri: &str) -> Result<()> {
+    self.uri_len = write_uri(&mut self.uri, uri)?;
+
+    Ok(())
+  }
+}
+
+// Copies `uri` into a fixed buffer, zeroing the tail, and returns its length.
+fn write_uri(buffer: &mut [u8; MAX_URI_LENGTH], uri: &str) -> Result<u32> {
+  require!(uri.len() <= MAX_URI_LENGTH, NewsError::UriTooLong);
+
+  *buffer = [0; MAX_URI_LENGTH];
+  buffer[..uri.len()].copy_from_slice(uri.as_bytes());
+
+  Ok(uri.len() as u32)
+}
+
+// Maps the numeric role codes of the legacy `User.reporters` array onto permission presets.
+pub fn role_permissions(role: u32) -> u32 {
+  match role {
+    ADMIN_ROLE => ADMIN_PERMISSIONS,
+    SENIOR_ROLE => SENIOR_PERMISSIONS,
+    _ => JUNIOR_PERMISSIONS,
   }
 }
 
@@ -161,6 +815,7 @@ pub struct CampaignPool {
   pub created_at: u32,
   pub updated_at: u32,
   pub state: u32,
+  pub fee: u64,   // campaign fee escrowed in Config.campaign_vault at creation, refunded on deny
 }
 
 impl Default for CampaignPool {
@@ -168,17 +823,220 @@ impl Default for CampaignPool {
   fn default() -> CampaignPool {
     CampaignPool {
           campaign_id: 0,
-          advertiser: anchor_lang::solana_program::pubkey!("B9NhXx7Dq3JJ7kRw9dEXNU4BR3vdMxeqVWoJc97itfGY"),
+          advertiser: Pubkey::default(),
           created_at: 0,
           updated_at: 0,
           state: 0,
+          fee: 0,
       }
   }
 }
 
+impl CampaignPool {
+  // The campaign fee stays escrowed until an admin approves or denies.
+  pub fn is_under_review(&self) -> bool {
+    self.state == 0 || self.state == 1
+  }
+
+  pub fn validate_reviewer(&self, reviewer: Pubkey) -> Result<()> {
+    require!(self.advertiser != reviewer, NewsError::ConflictOfInterest);
+
+    Ok(())
+  }
+}
+
 #[zero_copy]
 #[derive(Default, AnchorSerialize, AnchorDeserialize)]
 pub struct Reporter {
   pub reporter: Pubkey,
-  pub role: u32 //  1: senior, 2: admin
-}
\ No newline at end of file
+  pub role: u32 //  0: junior, 1: senior, 2: admin
+}
+
+#[zero_copy]
+#[derive(Default, AnchorSerialize, AnchorDeserialize)]
+pub struct AuditEntry {
+  pub actor: Pubkey,
+  pub target: Pubkey,
+  pub amount: u64,
+  pub action: u32,        // one of the AUDIT_* codes
+  pub timestamp: u32
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct AuditLog {
+  pub entries: [AuditEntry; MAX_AUDIT_ENTRY_COUNT],
+  pub head: u32,          // slot the next entry is written to
+  pub total: u64          // entries ever appended, including overwritten ones
+}
+
+impl Default for AuditLog {
+  #[inline]
+  fn default() -> AuditLog {
+    AuditLog {
+          entries: [
+            AuditEntry {
+                    ..Default::default()
+                }; MAX_AUDIT_ENTRY_COUNT
+          ],
+          head: 0,
+          total: 0
+      }
+  }
+}
+
+// Once full, each append overwrites the oldest entry; `total` keeps counting so
+// readers can tell how many entries have been dropped.
+impl AuditLog {
+  pub fn append(&mut self, actor: Pubkey, action: u32, target: Pubkey, amount: u64, timestamp: u32) {
+    self.entries[self.head as usize] = AuditEntry {
+      actor,
+      target,
+      amount,
+      action,
+      timestamp
+    };
+    self.head = (self.head + 1) % MAX_AUDIT_ENTRY_COUNT as u32;
+    self.total += 1;
+  }
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Multisig {
+  pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+  pub signer_count: u32,
+  pub threshold: u32,
+  pub generation: u32,    // bumped whenever the signer set changes, staling open proposals
+  pub proposal_count: u32
+}
+
+impl Default for Multisig {
+  #[inline]
+  fn default() -> Multisig {
+      Multisig {
+          signers: [Pubkey::default(); MAX_MULTISIG_SIGNERS],
+          signer_count: 0,
+          threshold: 0,
+          generation: 0,
+          proposal_count: 0
+      }
+  }
+}
+
+impl Multisig {
+  pub fn find_signer(&self, signer: Pubkey) -> Option<usize> {
+    self.signers[..self.signer_count as usize]
+      .iter()
+      .position(|item| *item == signer)
+  }
+
+  pub fn set_signers(&mut self, signers: &[Pubkey], threshold: u32) -> Result<()> {
+    require!(
+      !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+      NewsError::InvalidMultisig
+    );
+    require!(
+      threshold > 0 && threshold as usize <= signers.len(),
+      NewsError::InvalidMultisig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+      require!(!signers[..i].contains(signer), NewsError::InvalidMultisig);
+    }
+
+    self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+    self.signers[..signers.len()].copy_from_slice(signers);
+    self.signer_count = signers.len() as u32;
+    self.threshold = threshold;
+    self.generation += 1;
+
+    Ok(())
+  }
+}
+
+#[account(zero_copy)]
+// #[repr(packed)]
+pub struct Proposal {
+  pub multisig: Pubkey,
+  pub proposer: Pubkey,
+  pub index: u32,
+  pub generation: u32,
+  pub approvals: u32,     // bitmask over Multisig.signers
+  pub executed: u32,
+  pub created_at: u32,
+  pub action_len: u32,
+  pub action: [u8; MAX_ACTION_SIZE]   // borsh-encoded ProposalAction
+}
+
+impl Default for Proposal {
+  #[inline]
+  fn default() -> Proposal {
+      Proposal {
+          multisig: Pubkey::default(),
+          proposer: Pubkey::default(),
+          index: 0,
+          generation: 0,
+          approvals: 0,
+          executed: 0,
+          created_at: 0,
+          action_len: 0,
+          action: [0; MAX_ACTION_SIZE]
+      }
+  }
+}
+
+impl Proposal {
+  pub fn set_action(&mut self, action: &ProposalAction) -> Result<()> {
+    let data = action.try_to_vec()?;
+    require!(data.len() <= MAX_ACTION_SIZE, NewsError::InvalidProposalAction);
+
+    self.action[..data.len()].copy_from_slice(&data);
+    self.action_len = data.len() as u32;
+
+    Ok(())
+  }
+
+  pub fn approve(&mut self, multisig: &Multisig, signer: Pubkey) -> Result<()> {
+    require!(self.executed == 0, NewsError::ProposalExecuted);
+    require!(self.generation == multisig.generation, NewsError::ProposalStale);
+
+    let index = match multisig.find_signer(signer) {
+      Some(index) => index,
+      None => return err!(NewsError::NotMultisigSigner)
+    };
+    require!(self.approvals & (1 << index) == 0, NewsError::AlreadyApproved);
+
+    self.approvals |= 1 << index;
+
+    Ok(())
+  }
+
+  // Checks the threshold, marks the proposal executed and hands back the action to perform.
+  pub fn execute(&mut self, multisig: &Multisig) -> Result<ProposalAction> {
+    require!(self.executed == 0, NewsError::ProposalExecuted);
+    require!(self.generation == multisig.generation, NewsError::ProposalStale);
+    require!(
+      self.approvals.count_ones() >= multisig.threshold,
+      NewsError::ThresholdNotReached
+    );
+
+    self.executed = 1;
+
+    let action = ProposalAction::try_from_slice(&self.action[..self.action_len as usize])?;
+    Ok(action)
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+  Withdraw { amount: u64 },
+  WithdrawAll,
+  EditVault { vault: Pubkey, balance: u64 },
+  CreateAdmin { admin: Pubkey, expires_at: u32 },
+  SetSigners { signers: Vec<Pubkey>, threshold: u32 },
+  ProposeOwner { new_owner: Pubkey },  // Pubkey::default() cancels a pending transfer
+  UpdateConfig { news_payout: u64, campaign_fee: u64, campaign_vault: Pubkey },
+  UpdateReviewPolicy { approval_quorum: u32, deny_policy: u32, max_resubmissions: u32, review_deadline: u32 },
+  EditReporter { reporter: Pubkey, permissions: u32 },
+  MigrateReporter { reporter: Pubkey },
+}
@@ -1,13 +1,99 @@
-use anchor_lang::prelude::Pubkey;
-
 pub const POOL_SEED: &str = "pool_";
 pub const VAULT_SEED: &str = "vault_";
 pub const OWNER_VAULT_SEED: &str = "ownervault_";
 pub const USER_SEED: &str = "user_";
 pub const CAMPAIGN_SEED: &str = "campaign_";
-
-pub const OWNER_KEY: Pubkey = anchor_lang::solana_program::pubkey!("hFPVCyAan8HVuC3AVM34usCqUcEnqmDjdkHPk5NrAKW"); 
+pub const CONFIG_SEED: &str = "config_";
+pub const MEMBER_SEED: &str = "member_";
+pub const APPLICATION_SEED: &str = "application_";
+pub const MULTISIG_SEED: &str = "multisig_";
+pub const PROPOSAL_SEED: &str = "proposal_";
+pub const AUDIT_LOG_SEED: &str = "auditlog_";
+pub const PROFILE_SEED: &str = "profile_";
+pub const REVISION_SEED: &str = "revision_";
+pub const NEWS_APPROVAL_SEED: &str = "newsapproval_";
+pub const CORRECTION_SEED: &str = "correction_";
+pub const CATEGORY_SEED: &str = "category_";
+pub const CATEGORY_EDITOR_SEED: &str = "categoryeditor_";
+pub const NEWS_REGISTRY_SEED: &str = "newsregistry_";
+pub const INDEX_PAGE_SEED: &str = "indexpage_";
+pub const EDITOR_ESCALATION_SEED: &str = "editorescalation_";
 
 pub const MAX_REPORTER_COUNT: usize = 100;
-pub const FIXED_SOL: u64 = 100000000;
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_ACTION_SIZE: usize = 512;
+pub const MAX_AUDIT_ENTRY_COUNT: usize = 100;
+pub const MAX_URI_LENGTH: usize = 200;
+pub const FIRST_REVISION: u32 = 1;
+pub const DEFAULT_MAX_RESUBMISSIONS: u32 = 3;
+pub const MAX_TAG_COUNT: usize = 5;
+pub const MAX_COAUTHOR_COUNT: usize = 5;
+pub const TOTAL_SHARE_BPS: u32 = 10000;
+pub const INDEX_PAGE_SIZE: u64 = 100;
+pub const DELETED_INDEX_STATE: u32 = u32::MAX;
+pub const SENIOR_ROLE: u32 = 1;
+pub const ADMIN_ROLE: u32 = 2;
+
+pub const SUBMIT_PERMISSION: u32 = 1;
+pub const REVIEW_PERMISSION: u32 = 1 << 1;
+pub const PUBLISH_PERMISSION: u32 = 1 << 2;
+pub const PAYOUT_PERMISSION: u32 = 1 << 3;
+pub const CAMPAIGN_PERMISSION: u32 = 1 << 4;
+pub const ROLE_PERMISSION: u32 = 1 << 5;
+pub const TREASURY_PERMISSION: u32 = 1 << 6;   // member-driven spending from the owner vault
+pub const ALL_PERMISSIONS: u32 = (1 << 7) - 1;
+// Once a multisig is set, only a passed proposal can hand these out.
+pub const PROPOSAL_PERMISSIONS: u32 = ROLE_PERMISSION | TREASURY_PERMISSION;
+
+pub const JUNIOR_PERMISSIONS: u32 = SUBMIT_PERMISSION;
+pub const SENIOR_PERMISSIONS: u32 = JUNIOR_PERMISSIONS | REVIEW_PERMISSION;
+pub const ADMIN_PERMISSIONS: u32 = ALL_PERMISSIONS;
+
+pub const ACTIVE_STATUS: u32 = 0;
+pub const SUSPENDED_STATUS: u32 = 1;
+
+pub const DENY_BLOCKS_POLICY: u32 = 0;
+pub const DENY_RESETS_POLICY: u32 = 1;
+
+pub const AUDIT_UPDATE_CONFIG: u32 = 1;
+pub const AUDIT_PROPOSE_OWNER: u32 = 2;
+pub const AUDIT_ACCEPT_OWNER: u32 = 3;
+pub const AUDIT_CANCEL_OWNER_TRANSFER: u32 = 4;
+pub const AUDIT_WITHDRAW: u32 = 5;
+pub const AUDIT_CREATE_ADMIN: u32 = 6;
+pub const AUDIT_CREATE_SENIOR: u32 = 7;
+pub const AUDIT_EDIT_REPORTER: u32 = 8;
+pub const AUDIT_DELETE_REPORTER: u32 = 9;
+pub const AUDIT_SUSPEND_REPORTER: u32 = 10;
+pub const AUDIT_REINSTATE_REPORTER: u32 = 11;
+pub const AUDIT_RENEW_ROLE: u32 = 12;
+pub const AUDIT_MIGRATE_REPORTER: u32 = 13;
+pub const AUDIT_ACCEPT_APPLICATION: u32 = 14;
+pub const AUDIT_REJECT_APPLICATION: u32 = 15;
+pub const AUDIT_SET_SIGNERS: u32 = 16;
+pub const AUDIT_EDIT_VAULT: u32 = 17;
+pub const AUDIT_APPROVE_NEWS: u32 = 18;
+pub const AUDIT_DENY_NEWS: u32 = 19;
+pub const AUDIT_PUBLISH_NEWS: u32 = 20;
+pub const AUDIT_PAYOUT_JUNIOR: u32 = 21;
+pub const AUDIT_APPROVE_CAMPAIGN: u32 = 22;
+pub const AUDIT_DENY_CAMPAIGN: u32 = 23;
+pub const AUDIT_UPDATE_REVIEW_POLICY: u32 = 24;
+pub const AUDIT_SCHEDULE_PUBLISH: u32 = 25;
+pub const AUDIT_CANCEL_SCHEDULED_PUBLISH: u32 = 26;
+pub const AUDIT_RETRACT_NEWS: u32 = 27;
+pub const AUDIT_ISSUE_CORRECTION: u32 = 28;
+pub const AUDIT_CREATE_CATEGORY: u32 = 29;
+pub const AUDIT_EDIT_CATEGORY: u32 = 30;
+pub const AUDIT_GRANT_CATEGORY_EDITOR: u32 = 31;
+pub const AUDIT_REVOKE_CATEGORY_EDITOR: u32 = 32;
+pub const AUDIT_ESCALATE_REVIEW: u32 = 33;
+pub const AUDIT_CREATE_OWNER_VAULT: u32 = 34;
+pub const AUDIT_DEPOSIT: u32 = 35;
+pub const AUDIT_CREATE_USER: u32 = 36;
+pub const AUDIT_PRUNE_EXPIRED_ROLE: u32 = 37;
+pub const AUDIT_CREATE_PROPOSAL: u32 = 38;
+pub const AUDIT_APPROVE_PROPOSAL: u32 = 39;
+pub const AUDIT_CREATE_NEWS_REGISTRY: u32 = 40;
+
 pub const DECIMAL: u64 = 1000000000;
@@ -3,10 +3,113 @@ use std::mem::size_of;
 
 use crate::account::*;
 use crate::constants::*;
+use crate::errors::*;
+use crate::program::News;
+
+#[derive(Accounts)]
+pub struct InitializeConfigContext<'info> {
+  #[account(mut)]
+  pub authority: Signer<'info>,
+  #[account(init, seeds = [
+    CONFIG_SEED.as_bytes()], 
+    bump, 
+    payer = authority, 
+    space = size_of::<Config>() + 8
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(init, seeds = [
+    AUDIT_LOG_SEED.as_bytes()], 
+    bump, 
+    payer = authority, 
+    space = size_of::<AuditLog>() + 8
+  )]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+  pub program: Program<'info, News>,
+  #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ NewsError::NotUpgradeAuthority)]
+  pub program_data: Account<'info, ProgramData>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfigContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct UpdateReviewPolicyContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ProposeOwnerContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub new_owner: AccountInfo<'info>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired, 
+    constraint = config.load()?.owner_vault != Pubkey::default() @ NewsError::OwnerVaultNotCreated
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwnerContext<'info> {
+  #[account(mut, constraint = new_owner.key() == config.load()?.pending_owner @ NewsError::NotPendingOwner)]
+  pub new_owner: Signer<'info>,
+  #[account(mut, seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  #[account(mut, address = config.load()?.owner_vault)]
+  pub owner_vault: AccountLoader<'info, OwnerVault>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CancelOwnerTransferContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
 
 #[derive(Accounts)]
 pub struct CreateOwnerVaultContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
   #[account(init, seeds = [
     OWNER_VAULT_SEED.as_bytes(), 
@@ -16,39 +119,67 @@ pub struct CreateOwnerVaultContext<'info> {
     space = size_of::<OwnerVault>() + 8
   )]
   pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.owner_vault == Pubkey::default() @ NewsError::AlreadyInitialized
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct DepositContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, address = config.load()?.owner_vault)]
   pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct WihdrawContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, address = config.load()?.owner_vault)]
   pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct WihdrawAllContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, address = config.load()?.owner_vault)]
   pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct CreateUserContext<'info> {
-  #[account(mut)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
   #[account(init, seeds = [
     USER_SEED.as_bytes(), 
@@ -58,18 +189,43 @@ pub struct CreateUserContext<'info> {
     space = size_of::<User>() + 8
   )]
   pub user: AccountLoader<'info, User>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.user == Pubkey::default() @ NewsError::AlreadyInitialized
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct CreateAdminContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
   #[account(mut)]
     /// CHECK: it's not dangerous
   pub admin: AccountInfo<'info>,
-  #[account(mut)]
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump, 
+    payer = owner, 
+    space = size_of::<Member>() + 8
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
@@ -80,35 +236,574 @@ pub struct CreateSeniorContext<'info> {
   #[account(mut)]
     /// CHECK: it's not dangerous
   pub senior: AccountInfo<'info>,
-  #[account(mut)]
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    senior.key().as_ref()], 
+    bump, 
+    payer = admin, 
+    space = size_of::<Member>() + 8
+  )]
+  pub senior_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
 pub struct EditReporterContext<'info> {
   #[account(mut)]
-  pub owner: Signer<'info>,
+  pub admin: Signer<'info>,
   /// CHECK: it's not dangerous
   #[account(mut)]
-  pub old_reporter: AccountInfo<'info>,
+  pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct DeleteReporterContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
   /// CHECK: it's not dangerous
   #[account(mut)]
   pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SuspendReporterContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
   #[account(mut)]
+  pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-pub struct DeleteReporterContext<'info> {
+pub struct ReinstateReporterContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
+  #[account(mut)]
+  pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct RenewRoleContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct PruneExpiredRoleContext<'info> {
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct MigrateReporterContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub reporter: AccountInfo<'info>,
+  #[account(mut, address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump, 
+    payer = owner, 
+    space = size_of::<Member>() + 8
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ApplyAsReporterContext<'info> {
+  #[account(mut)]
+  pub applicant: Signer<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(init, seeds = [
+    APPLICATION_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    applicant.key().as_ref()], 
+    bump, 
+    payer = applicant, 
+    space = size_of::<Application>() + 8
+  )]
+  pub application: AccountLoader<'info, Application>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateProfileContext<'info> {
+  #[account(mut)]
+  pub reporter: Signer<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(init, seeds = [
+    PROFILE_SEED.as_bytes(), 
+    reporter.key().as_ref()], 
+    bump, 
+    payer = reporter, 
+    space = size_of::<ReporterProfile>() + 8
+  )]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfileContext<'info> {
+  #[account(mut)]
+  pub reporter: Signer<'info>,
+  #[account(mut, seeds = [
+    PROFILE_SEED.as_bytes(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct AcceptApplicationContext<'info> {
   #[account(mut)]
-  pub admain: Signer<'info>,
+  pub admin: Signer<'info>,
   /// CHECK: it's not dangerous
   #[account(mut)]
+  pub applicant: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    APPLICATION_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    applicant.key().as_ref()], 
+    bump
+  )]
+  pub application: AccountLoader<'info, Application>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    applicant.key().as_ref()], 
+    bump, 
+    payer = admin, 
+    space = size_of::<Member>() + 8
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct RejectApplicationContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
+  #[account(mut)]
+  pub applicant: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    APPLICATION_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    applicant.key().as_ref()], 
+    bump
+  )]
+  pub application: AccountLoader<'info, Application>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisigContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  #[account(init, seeds = [
+    MULTISIG_SEED.as_bytes()], 
+    bump, 
+    payer = owner, 
+    space = size_of::<Multisig>() + 8
+  )]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, 
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::AlreadyInitialized
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateProposalContext<'info> {
+  #[account(mut)]
+  pub proposer: Signer<'info>,
+  #[account(mut, address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(init, seeds = [
+    PROPOSAL_SEED.as_bytes(), 
+    multisig.key().as_ref(), 
+    &multisig.load()?.proposal_count.to_le_bytes()], 
+    bump, 
+    payer = proposer, 
+    space = size_of::<Proposal>() + 8
+  )]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposalContext<'info> {
+  #[account(mut)]
+  pub signer: Signer<'info>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  /// CHECK: it's not dangerous
+  #[account(mut, address = config.load()?.owner)]
+  pub owner: AccountInfo<'info>,
+  #[account(mut, address = config.load()?.owner_vault)]
+  pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEditVaultContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  #[account(mut)]
+  pub vault: AccountLoader<'info, Vault>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteCreateAdminContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub admin: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump, 
+    payer = executor, 
+    space = size_of::<Member>() + 8
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSetSignersContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  #[account(mut, address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposeOwnerContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(mut, seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteUpdateConfigContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(mut, seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEditReporterContext<'info> {
+  #[account(mut)]
+  pub executor: Signer<'info>,
+  /// CHECK: it's not dangerous
   pub reporter: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(mut, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMigrateReporterContext<'info> {
   #[account(mut)]
+  pub executor: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub reporter: AccountInfo<'info>,
+  #[account(mut, address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(init, seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump, 
+    payer = executor, 
+    space = size_of::<Member>() + 8
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(address = config.load()?.multisig)]
+  pub multisig: AccountLoader<'info, Multisig>,
+  #[account(mut, constraint = proposal.load()?.multisig == multisig.key() @ NewsError::InvalidProposal)]
+  pub proposal: AccountLoader<'info, Proposal>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
@@ -129,10 +824,177 @@ pub struct CreateVaultContext<'info> {
 
 #[derive(Accounts)]
 pub struct EditVaultContext<'info> {
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: Signer<'info>,
   #[account(mut)]
   pub vault: AccountLoader<'info, Vault>,
+  #[account(
+    seeds = [CONFIG_SEED.as_bytes()], 
+    bump, 
+    constraint = config.load()?.multisig == Pubkey::default() @ NewsError::MultisigRequired
+  )]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateNewsRegistryContext<'info> {
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
+  pub owner: Signer<'info>,
+  #[account(init, seeds = [
+    NEWS_REGISTRY_SEED.as_bytes()], 
+    bump, 
+    payer = owner, 
+    space = size_of::<NewsRegistry>() + 8
+  )]
+  pub news_registry: AccountLoader<'info, NewsRegistry>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CreateIndexPageContext<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(mut, seeds = [NEWS_REGISTRY_SEED.as_bytes()], bump)]
+  pub news_registry: AccountLoader<'info, NewsRegistry>,
+  #[account(init, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &news_registry.load()?.page_count.to_le_bytes()], 
+    bump, 
+    payer = payer, 
+    space = size_of::<IndexPage>() + 8
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SyncIndexEntryContext<'info> {
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+}
+
+#[derive(Accounts)]
+#[instruction(category_id: u32)]
+pub struct CreateCategoryContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  #[account(init, seeds = [
+    CATEGORY_SEED.as_bytes(), 
+    &category_id.to_le_bytes()], 
+    bump, 
+    payer = admin, 
+    space = size_of::<Category>() + 8
+  )]
+  pub category: AccountLoader<'info, Category>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct EditCategoryContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  #[account(mut)]
+  pub category: AccountLoader<'info, Category>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct GrantCategoryEditorContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub editor: AccountInfo<'info>,
+  pub category: AccountLoader<'info, Category>,
+  #[account(init, seeds = [
+    CATEGORY_EDITOR_SEED.as_bytes(), 
+    category.key().as_ref(), 
+    editor.key().as_ref()], 
+    bump, 
+    payer = admin, 
+    space = size_of::<CategoryEditor>() + 8
+  )]
+  pub category_editor: AccountLoader<'info, CategoryEditor>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct RevokeCategoryEditorContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  /// CHECK: it's not dangerous
+  pub editor: AccountInfo<'info>,
+  pub category: AccountLoader<'info, Category>,
+  #[account(mut, seeds = [
+    CATEGORY_EDITOR_SEED.as_bytes(), 
+    category.key().as_ref(), 
+    editor.key().as_ref()], 
+    bump
+  )]
+  pub category_editor: AccountLoader<'info, CategoryEditor>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
 
@@ -150,6 +1012,41 @@ pub struct CreateNewsContext<'info> {
     space = size_of::<Pool>() + 8
   )]
   pub pool: AccountLoader<'info, Pool>,
+  #[account(init, seeds = [
+    REVISION_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    &FIRST_REVISION.to_le_bytes()], 
+    bump, 
+    payer = reporter, 
+    space = size_of::<Revision>() + 8
+  )]
+  pub revision: AccountLoader<'info, Revision>,
+  pub category: AccountLoader<'info, Category>,
+  #[account(mut, seeds = [NEWS_REGISTRY_SEED.as_bytes()], bump)]
+  pub news_registry: AccountLoader<'info, NewsRegistry>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(news_registry.load()?.count / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(mut, seeds = [
+    PROFILE_SEED.as_bytes(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
   pub system_program: Program<'info, System>
 }
 
@@ -157,17 +1054,129 @@ pub struct CreateNewsContext<'info> {
 pub struct EditNewsContext<'info> {
   #[account(mut)]
   pub reporter: Signer<'info>,
+  #[account(mut, constraint = pool.load()?.reporter == reporter.key() @ NewsError::NotNewsReporter)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(init, seeds = [
+    REVISION_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    &(pool.load()?.revision + 1).to_le_bytes()], 
+    bump, 
+    payer = reporter, 
+    space = size_of::<Revision>() + 8
+  )]
+  pub revision: AccountLoader<'info, Revision>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ResubmitNewsContext<'info> {
   #[account(mut)]
+  pub reporter: Signer<'info>,
+  #[account(mut, constraint = pool.load()?.reporter == reporter.key() @ NewsError::NotNewsReporter)]
   pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(init, seeds = [
+    REVISION_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    &(pool.load()?.revision + 1).to_le_bytes()], 
+    bump, 
+    payer = reporter, 
+    space = size_of::<Revision>() + 8
+  )]
+  pub revision: AccountLoader<'info, Revision>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    reporter.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
   pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-pub struct DeleteNewsContext<'info> {
+pub struct SetCoAuthorsContext<'info> {
   #[account(mut)]
   pub reporter: Signer<'info>,
+  #[account(mut, constraint = pool.load()?.reporter == reporter.key() @ NewsError::NotNewsReporter)]
+  pub pool: AccountLoader<'info, Pool>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct AcceptCoAuthorshipContext<'info> {
+  #[account(mut)]
+  pub coauthor: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    coauthor.key().as_ref()], 
+    bump
+  )]
+  pub member: AccountLoader<'info, Member>,
+  // Both must exist so the co-author's share can be credited and paid out.
+  #[account(seeds = [
+    PROFILE_SEED.as_bytes(), 
+    coauthor.key().as_ref()], 
+    bump
+  )]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(seeds = [
+    VAULT_SEED.as_bytes(), 
+    coauthor.key().as_ref()], 
+    bump
+  )]
+  pub vault: AccountLoader<'info, Vault>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct DeleteNewsContext<'info> {
   #[account(mut)]
+  pub reporter: Signer<'info>,
+  #[account(mut, 
+    constraint = pool.load()?.reporter == reporter.key() @ NewsError::NotNewsReporter, 
+    constraint = pool.load()?.news_state()?.is_closable() @ NewsError::NewsNotClosable
+  )]
   pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
   pub system_program: Program<'info, System>
 }
 
@@ -177,8 +1186,48 @@ pub struct ApproveNewsContext<'info> {
   pub senior: Signer<'info>,
   #[account(mut)]
   pub pool: AccountLoader<'info, Pool>,
-  #[account(mut)]
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(init, seeds = [
+    NEWS_APPROVAL_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    &pool.load()?.approval_round.to_le_bytes(), 
+    senior.key().as_ref()], 
+    bump, 
+    payer = senior, 
+    space = size_of::<NewsApproval>() + 8
+  )]
+  pub approval: AccountLoader<'info, NewsApproval>,
+  #[account(mut, constraint = profile.load()?.reporter == pool.load()?.reporter @ NewsError::InvalidProfile)]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    senior.key().as_ref()], 
+    bump
+  )]
+  pub senior_member: AccountLoader<'info, Member>,
+  #[account(address = pool.load()?.category)]
+  pub category: AccountLoader<'info, Category>,
+  /// CHECK: must be the senior's CategoryEditor grant unless the article is escalated
+  #[account(seeds = [
+    CATEGORY_EDITOR_SEED.as_bytes(), 
+    category.key().as_ref(), 
+    senior.key().as_ref()], 
+    bump
+  )]
+  pub category_editor: AccountInfo<'info>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
@@ -187,37 +1236,273 @@ pub struct DenyNewsContext<'info> {
   pub senior: Signer<'info>,
   #[account(mut)]
   pub pool: AccountLoader<'info, Pool>,
-  #[account(mut)]
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(mut, constraint = profile.load()?.reporter == pool.load()?.reporter @ NewsError::InvalidProfile)]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    senior.key().as_ref()], 
+    bump
+  )]
+  pub senior_member: AccountLoader<'info, Member>,
+  #[account(address = pool.load()?.category)]
+  pub category: AccountLoader<'info, Category>,
+  /// CHECK: must be the senior's CategoryEditor grant unless the article is escalated
+  #[account(seeds = [
+    CATEGORY_EDITOR_SEED.as_bytes(), 
+    category.key().as_ref(), 
+    senior.key().as_ref()], 
+    bump
+  )]
+  pub category_editor: AccountInfo<'info>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct EscalateReviewContext<'info> {
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, address = pool.load()?.category)]
+  pub category: AccountLoader<'info, Category>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CountEditorEscalationContext<'info> {
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+  #[account(constraint = pool.load()?.escalated_at != 0 @ NewsError::ReviewNotEscalated)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, constraint = category_editor.load()?.category == pool.load()?.category @ NewsError::InvalidCategoryEditors)]
+  pub category_editor: AccountLoader<'info, CategoryEditor>,
+  #[account(init, seeds = [
+    EDITOR_ESCALATION_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    category_editor.key().as_ref(), 
+    &pool.load()?.escalated_at.to_le_bytes()], 
+    bump, 
+    payer = cranker, 
+    space = size_of::<EditorEscalation>() + 8
+  )]
+  pub escalation: AccountLoader<'info, EditorEscalation>,
+  pub system_program: Program<'info, System>
+}
+
 #[derive(Accounts)]
 pub struct PublishNewsContext<'info> {
   #[account(mut,)]
   pub admin: Signer<'info>,
   #[account(mut)]
   pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(mut, constraint = profile.load()?.reporter == pool.load()?.reporter @ NewsError::InvalidProfile)]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  // Credited only when the article has no co-authors; credit_author_vaults checks its owner.
   #[account(mut)]
   pub vault: AccountLoader<'info, Vault>,
-  #[account(mut)]
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct PayoutJuniorContext<'info> {
+pub struct RetractNewsContext<'info> {
   #[account(mut)]
   pub admin: Signer<'info>,
-    /// CHECK: it's not dangerous
   #[account(mut)]
-  pub junior: AccountInfo<'info>,
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  // Debited only when the article has no co-authors; debit_author_vaults checks its owner.
   #[account(mut)]
+  pub vault: AccountLoader<'info, Vault>,
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct IssueCorrectionContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(init, seeds = [
+    CORRECTION_SEED.as_bytes(), 
+    pool.key().as_ref(), 
+    &pool.load()?.correction_count.to_le_bytes()], 
+    bump, 
+    payer = admin, 
+    space = size_of::<Correction>() + 8
+  )]
+  pub correction: AccountLoader<'info, Correction>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct SchedulePublishContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct CancelScheduledPublishContext<'info> {
+  #[account(mut)]
+  pub admin: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct ExecuteScheduledPublishContext<'info> {
+  #[account(mut)]
+  pub cranker: Signer<'info>,
+  #[account(mut)]
+  pub pool: AccountLoader<'info, Pool>,
+  #[account(mut, seeds = [
+    INDEX_PAGE_SEED.as_bytes(), 
+    &(pool.load()?.sequence / INDEX_PAGE_SIZE).to_le_bytes()], 
+    bump
+  )]
+  pub index_page: AccountLoader<'info, IndexPage>,
+  #[account(mut, constraint = profile.load()?.reporter == pool.load()?.reporter @ NewsError::InvalidProfile)]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  // Credited only when the article has no co-authors; credit_author_vaults checks its owner.
   #[account(mut)]
   pub vault: AccountLoader<'info, Vault>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
+  pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+pub struct PayoutJuniorContext<'info> {
   #[account(mut)]
+  pub admin: Signer<'info>,
+    /// CHECK: it's not dangerous
+  #[account(mut)]
+  pub junior: AccountInfo<'info>,
+  #[account(address = config.load()?.user)]
+  pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  /// CHECK: the junior's Member PDA; it may already be closed by delete_reporter or prune_expired_role
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    junior.key().as_ref()], 
+    bump
+  )]
+  pub junior_member: AccountInfo<'info>,
+  #[account(mut, constraint = profile.load()?.reporter == junior.key() @ NewsError::InvalidProfile)]
+  pub profile: AccountLoader<'info, ReporterProfile>,
+  #[account(mut, constraint = vault.load()?.reporter == junior.key() @ NewsError::NotVaultReporter)]
+  pub vault: AccountLoader<'info, Vault>,
+  #[account(mut, address = config.load()?.owner_vault)]
   pub owner_vault: AccountLoader<'info, OwnerVault>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>,
 }
 
@@ -236,8 +1521,10 @@ pub struct SendMintFeeContext<'info> {
   #[account(mut)]
   pub reporter: Signer<'info>,
     /// CHECK: it's not dangerous
-  #[account(mut, constraint = owner.key() == OWNER_KEY)]
+  #[account(mut, constraint = owner.key() == config.load()?.owner @ NewsError::NotOwner)]
   pub owner: AccountInfo<'info>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
   pub system_program: Program<'info, System>,
 }
 
@@ -246,8 +1533,8 @@ pub struct SendMintFeeContext<'info> {
 pub struct CreateCampaignContext<'info> {
   #[account(mut)]
   pub advertiser: Signer<'info>,
-  /// CHECK: it's not dangerous
-  #[account(mut)]
+  /// CHECK: the configured campaign vault
+  #[account(mut, address = config.load()?.campaign_vault)]
   pub vault: AccountInfo<'info>,
   #[account(init, seeds = [
     CAMPAIGN_SEED.as_bytes(), 
@@ -258,6 +1545,8 @@ pub struct CreateCampaignContext<'info> {
     space = size_of::<CampaignPool>() + 8
   )]
   pub campaign_pool: AccountLoader<'info, CampaignPool>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
   pub system_program: Program<'info, System>
 }
 
@@ -265,7 +1554,10 @@ pub struct CreateCampaignContext<'info> {
 pub struct EditCampaignContext<'info> {
   #[account(mut)]
   pub advertiser: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, 
+    constraint = campaign_pool.load()?.advertiser == advertiser.key() @ NewsError::NotCampaignAdvertiser, 
+    constraint = campaign_pool.load()?.is_under_review() @ NewsError::CampaignNotUnderReview
+  )]
   pub campaign_pool: AccountLoader<'info, CampaignPool>,
   pub system_program: Program<'info, System>
 }
@@ -274,7 +1566,10 @@ pub struct EditCampaignContext<'info> {
 pub struct DeleteCampaignContext<'info> {
   #[account(mut)]
   pub advertiser: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, 
+    constraint = campaign_pool.load()?.advertiser == advertiser.key() @ NewsError::NotCampaignAdvertiser, 
+    constraint = !campaign_pool.load()?.is_under_review() @ NewsError::CampaignUnderReview
+  )]
   pub campaign_pool: AccountLoader<'info, CampaignPool>,
   pub system_program: Program<'info, System>
 }
@@ -283,12 +1578,23 @@ pub struct DeleteCampaignContext<'info> {
 pub struct ApproveCampaignContext<'info> {
   #[account(mut)]
   pub admin: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, address = config.load()?.campaign_vault)]
   pub vault: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, constraint = campaign_pool.load()?.is_under_review() @ NewsError::CampaignNotUnderReview)]
   pub campaign_pool: AccountLoader<'info, CampaignPool>,
-  #[account(mut)]
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>,
 }
 
@@ -297,13 +1603,24 @@ pub struct DenyCampaignContext<'info> {
   #[account(mut)]
   pub admin: Signer<'info>,
   /// CHECK: it's not dangerous
-  #[account(mut)]
+  #[account(mut, constraint = advertiser.key() == campaign_pool.load()?.advertiser @ NewsError::NotCampaignAdvertiser)]
   pub advertiser: AccountInfo<'info>,
-  #[account(mut)]
+  #[account(mut, address = config.load()?.campaign_vault)]
   pub vault: Signer<'info>,
-  #[account(mut)]
+  #[account(mut, constraint = campaign_pool.load()?.is_under_review() @ NewsError::CampaignNotUnderReview)]
   pub campaign_pool: AccountLoader<'info, CampaignPool>,
-  #[account(mut)]
+  #[account(address = config.load()?.user)]
   pub user: AccountLoader<'info, User>,
+  #[account(seeds = [
+    MEMBER_SEED.as_bytes(), 
+    user.key().as_ref(), 
+    admin.key().as_ref()], 
+    bump
+  )]
+  pub admin_member: AccountLoader<'info, Member>,
+  #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+  pub config: AccountLoader<'info, Config>,
+  #[account(mut, seeds = [AUDIT_LOG_SEED.as_bytes()], bump)]
+  pub audit_log: AccountLoader<'info, AuditLog>,
   pub system_program: Program<'info, System>
 }
\ No newline at end of file
@@ -23,5 +23,115 @@ pub enum NewsError {
     #[msg("Edit Reporter Error!")]
     EditReporterError,
     #[msg("Delete Reporter Error!")]
-    DeleteReporterError
+    DeleteReporterError,
+    #[msg("Not Owner!")]
+    NotOwner,
+    #[msg("Not Upgrade Authority!")]
+    NotUpgradeAuthority,
+    #[msg("Not Pending Owner!")]
+    NotPendingOwner,
+    #[msg("Already Initialized!")]
+    AlreadyInitialized,
+    #[msg("Invalid News State!")]
+    InvalidNewsState,
+    #[msg("Invalid State Transition!")]
+    InvalidStateTransition,
+    #[msg("Not News Reporter!")]
+    NotNewsReporter,
+    #[msg("News Can't Be Deleted!")]
+    NewsNotClosable,
+    #[msg("Not Campaign Advertiser!")]
+    NotCampaignAdvertiser,
+    #[msg("Campaign Under Review!")]
+    CampaignUnderReview,
+    #[msg("Reporter Not Found!")]
+    ReporterNotFound,
+    #[msg("Duplicate Reporter!")]
+    DuplicateReporter,
+    #[msg("Missing Permission!")]
+    MissingPermission,
+    #[msg("Invalid Permissions!")]
+    InvalidPermissions,
+    #[msg("Multisig Required!")]
+    MultisigRequired,
+    #[msg("Invalid Multisig!")]
+    InvalidMultisig,
+    #[msg("Not Multisig Signer!")]
+    NotMultisigSigner,
+    #[msg("Already Approved!")]
+    AlreadyApproved,
+    #[msg("Threshold Not Reached!")]
+    ThresholdNotReached,
+    #[msg("Proposal Already Executed!")]
+    ProposalExecuted,
+    #[msg("Proposal Is Stale!")]
+    ProposalStale,
+    #[msg("Invalid Proposal!")]
+    InvalidProposal,
+    #[msg("Invalid Proposal Action!")]
+    InvalidProposalAction,
+    #[msg("Role Expired!")]
+    RoleExpired,
+    #[msg("Role Not Expired!")]
+    RoleNotExpired,
+    #[msg("Invalid Expiry!")]
+    InvalidExpiry,
+    #[msg("Reporter Suspended!")]
+    ReporterSuspended,
+    #[msg("Reporter Not Suspended!")]
+    ReporterNotSuspended,
+    #[msg("Not Vault Reporter!")]
+    NotVaultReporter,
+    #[msg("Uri Too Long!")]
+    UriTooLong,
+    #[msg("Invalid Profile!")]
+    InvalidProfile,
+    #[msg("Content Hash Mismatch!")]
+    ContentHashMismatch,
+    #[msg("Revision Mismatch!")]
+    RevisionMismatch,
+    #[msg("Invalid Review Policy!")]
+    InvalidReviewPolicy,
+    #[msg("News Denied!")]
+    NewsDenied,
+    #[msg("Resubmit Limit Reached!")]
+    ResubmitLimitReached,
+    #[msg("Conflict Of Interest!")]
+    ConflictOfInterest,
+    #[msg("Invalid Publish Time!")]
+    InvalidPublishTime,
+    #[msg("News Not Scheduled!")]
+    NewsNotScheduled,
+    #[msg("Publish Time Not Reached!")]
+    PublishTimeNotReached,
+    #[msg("Not Published News!")]
+    NotPublishedNews,
+    #[msg("Too Many Tags!")]
+    TooManyTags,
+    #[msg("Invalid Co-Authors!")]
+    InvalidCoAuthors,
+    #[msg("Invalid Shares!")]
+    InvalidShares,
+    #[msg("Not Co-Author!")]
+    NotCoAuthor,
+    #[msg("Co-Authors Not Accepted!")]
+    CoAuthorsNotAccepted,
+    #[msg("Invalid Vaults!")]
+    InvalidVaults,
+    #[msg("Not Category Editor!")]
+    NotCategoryEditor,
+    #[msg("Review Not Overdue!")]
+    ReviewNotOverdue,
+    #[msg("Already Escalated!")]
+    AlreadyEscalated,
+    #[msg("Invalid Category Editors!")]
+    InvalidCategoryEditors,
+    #[msg("Campaign Not Under Review!")]
+    CampaignNotUnderReview,
+    #[msg("Owner Vault Not Created!")]
+    OwnerVaultNotCreated,
+    #[msg("Review Not Escalated!")]
+    ReviewNotEscalated,
+    #[msg("Editor Granted After Deadline!")]
+    EditorGrantedAfterDeadline
 }
\ No newline at end of file
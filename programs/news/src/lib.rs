@@ -1,3 +1,5 @@
+#![allow(clippy::result_large_err)]
+
 use anchor_lang::prelude::*;
 
 pub mod contexts;
@@ -10,6 +12,7 @@ use contexts::*;
 use utils::*;
 use errors::*;
 use constants::*;
+use account::*;
 
 declare_id!("8LDu2mVLHTwomnBwXv5juTwnhDtjKndE6Z1U8BYSnzJh");
 
@@ -19,15 +22,150 @@ pub mod news {
 
     use anchor_lang::AccountsClose;
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfigContext>,
+        news_payout: u64,
+        campaign_fee: u64,
+        campaign_vault: Pubkey
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_init()?;
+        let a_authority = &ctx.accounts.authority;
+
+        a_config.owner = a_authority.to_account_info().key();
+        a_config.news_payout = news_payout;
+        a_config.campaign_fee = campaign_fee;
+        a_config.campaign_vault = campaign_vault;
+        a_config.approval_quorum = 1;
+        a_config.deny_policy = DENY_BLOCKS_POLICY;
+        a_config.max_resubmissions = DEFAULT_MAX_RESUBMISSIONS;
+
+        Ok(())
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfigContext>,
+        news_payout: u64,
+        campaign_fee: u64,
+        campaign_vault: Pubkey
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_mut()?;
+
+        a_config.news_payout = news_payout;
+        a_config.campaign_fee = campaign_fee;
+        a_config.campaign_vault = campaign_vault;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_UPDATE_CONFIG, 
+            ctx.accounts.config.key(), 
+            news_payout
+        )?;
+
+        Ok(())
+    }
+
+    pub fn update_review_policy(
+        ctx: Context<UpdateReviewPolicyContext>,
+        approval_quorum: u32,
+        deny_policy: u32,
+        max_resubmissions: u32,
+        review_deadline: u32
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_mut()?;
+
+        a_config.set_review_policy(approval_quorum, deny_policy, max_resubmissions, review_deadline)?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_UPDATE_REVIEW_POLICY, 
+            ctx.accounts.config.key(), 
+            approval_quorum as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn propose_owner(
+        ctx: Context<ProposeOwnerContext>
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_mut()?;
+        let a_new_owner = &ctx.accounts.new_owner;
+
+        a_config.pending_owner = a_new_owner.to_account_info().key();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_PROPOSE_OWNER, 
+            a_new_owner.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn accept_owner(
+        ctx: Context<AcceptOwnerContext>
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_mut()?;
+        let mut a_owner_vault = ctx.accounts.owner_vault.load_mut()?;
+        let a_new_owner = &ctx.accounts.new_owner;
+        let previous_owner = a_config.owner;
+
+        a_config.owner = a_new_owner.to_account_info().key();
+        a_config.pending_owner = Pubkey::default();
+        a_owner_vault.owner = a_new_owner.to_account_info().key();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_new_owner.key(), 
+            AUDIT_ACCEPT_OWNER, 
+            previous_owner, 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn cancel_owner_transfer(
+        ctx: Context<CancelOwnerTransferContext>
+    ) -> Result<()> {
+        let mut a_config = ctx.accounts.config.load_mut()?;
+        let pending_owner = a_config.pending_owner;
+
+        a_config.pending_owner = Pubkey::default();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_CANCEL_OWNER_TRANSFER, 
+            pending_owner, 
+            0
+        )?;
+
+        Ok(())
+    }
 
     pub fn create_owner_vault(
         ctx: Context<CreateOwnerVaultContext>
     ) -> Result<()> {
         let mut a_owner_vault = ctx.accounts.owner_vault.load_init()?;
+        let mut a_config = ctx.accounts.config.load_mut()?;
         let a_owner = &ctx.accounts.owner;
 
         a_owner_vault.owner = a_owner.to_account_info().key();
         a_owner_vault.balance = 0;
+        a_config.owner_vault = ctx.accounts.owner_vault.key();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_owner.key(), 
+            AUDIT_CREATE_OWNER_VAULT, 
+            ctx.accounts.owner_vault.key(), 
+            0
+        )?;
 
         Ok(())
     }
@@ -52,13 +190,20 @@ pub mod news {
             );
     
             anchor_lang::system_program::transfer(cpi_ctx, amount)?;
-            drop(owner_vault);
         }
         {
             let mut a_owner_vault = ctx.accounts.owner_vault.load_mut()?;
             a_owner_vault.balance += amount;
         }
 
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_DEPOSIT, 
+            ctx.accounts.owner_vault.key(), 
+            amount
+        )?;
+
         Ok(())
     }
 
@@ -66,51 +211,37 @@ pub mod news {
         ctx: Context<WihdrawContext>,
         amount: u64
     ) -> Result<()> {
-        {
-            let owner_vault = ctx.accounts.owner_vault.load()?;
-            let balance = owner_vault.balance;
-            require!(balance > 0,  NewsError::InsufficientFunds);
-        }
-        {
-            let _owner_vault = &mut ctx.accounts.owner_vault;
-            let owner = &mut ctx.accounts.owner;
+        let owner = &ctx.accounts.owner;
+
+        withdraw_from_owner_vault(&ctx.accounts.owner_vault, &owner.to_account_info(), amount)?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            owner.key(), 
+            AUDIT_WITHDRAW, 
+            ctx.accounts.owner_vault.key(), 
+            amount
+        )?;
 
-            let from = _owner_vault.to_account_info();
-            let to = owner.to_account_info();
-            **from.try_borrow_mut_lamports()? -= amount;
-            **to.try_borrow_mut_lamports()? += amount;
-        }
-        {
-            let mut a_owner_vault = ctx.accounts.owner_vault.load_mut()?;
-            a_owner_vault.balance = a_owner_vault.balance - amount;
-        }
-    } 
         Ok(())   
     }
 
     pub fn withdraw_all(
         ctx: Context<WihdrawAllContext>
     ) -> Result<()> {
-        {
-            {
-                let owner_vault = ctx.accounts.owner_vault.load()?;
-                let balance = owner_vault.balance;
-                require!(balance > 0,  NewsError::InsufficientFunds);
-            }
-            {
-                let _owner_vault = &mut ctx.accounts.owner_vault;
-                let owner = &mut ctx.accounts.owner;
-
-                let from = _owner_vault.to_account_info();
-                let to = owner.to_account_info();
-                **from.try_borrow_mut_lamports()? -= ctx.accounts.owner_vault.load()?.balance;
-                **to.try_borrow_mut_lamports()? += ctx.accounts.owner_vault.load()?.balance;
-            }
-            {
-                let mut a_owner_vault = ctx.accounts.owner_vault.load_mut()?;
-                a_owner_vault.balance = 0;
-            }
-        } 
+        let owner = &ctx.accounts.owner;
+        let balance = ctx.accounts.owner_vault.load()?.balance;
+
+        withdraw_from_owner_vault(&ctx.accounts.owner_vault, &owner.to_account_info(), balance)?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            owner.key(), 
+            AUDIT_WITHDRAW, 
+            ctx.accounts.owner_vault.key(), 
+            balance
+        )?;
+
         Ok(())
     }
 
@@ -118,76 +249,721 @@ pub mod news {
         ctx: Context<CreateUserContext>
     ) -> Result<()> {
         let mut a_user = ctx.accounts.user.load_init()?;
+        let mut a_config = ctx.accounts.config.load_mut()?;
+
         a_user.count = 0;
+        a_config.user = ctx.accounts.user.key();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_CREATE_USER, 
+            ctx.accounts.user.key(), 
+            0
+        )?;
 
         Ok(())
     }
 
     pub fn create_admin(
-        ctx: Context<CreateAdminContext>
+        ctx: Context<CreateAdminContext>,
+        expires_at: u32
     ) -> Result<()> {
-        let mut a_user = ctx.accounts.user.load_mut()?;
+        let mut a_admin_member = ctx.accounts.admin_member.load_init()?;
+        let a_user = &ctx.accounts.user;
         let a_admin = &ctx.accounts.admin;
 
+        validate_expires_at(expires_at)?;
+
+        a_admin_member.registry = a_user.key();
+        a_admin_member.reporter = a_admin.to_account_info().key();
+        a_admin_member.permissions = ADMIN_PERMISSIONS;
+        a_admin_member.status = ACTIVE_STATUS;
+        a_admin_member.joined_at = get_current_time()?;
+        a_admin_member.expires_at = expires_at;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_CREATE_ADMIN, 
+            a_admin.key(), 
+            expires_at as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_senior(
+        ctx: Context<CreateSeniorContext>,
+        expires_at: u32
+    ) -> Result<()> {
+        let mut a_senior_member = ctx.accounts.senior_member.load_init()?;
+        let a_user = &ctx.accounts.user;
+        let a_senior = &ctx.accounts.senior;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+        validate_expires_at(expires_at)?;
+
+        a_senior_member.registry = a_user.key();
+        a_senior_member.reporter = a_senior.to_account_info().key();
+        a_senior_member.permissions = SENIOR_PERMISSIONS;
+        a_senior_member.status = ACTIVE_STATUS;
+        a_senior_member.joined_at = get_current_time()?;
+        a_senior_member.expires_at = expires_at;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_CREATE_SENIOR, 
+            a_senior.key(), 
+            expires_at as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn edit_reporter(
+        ctx: Context<EditReporterContext>,
+        permissions: u32
+    ) -> Result<()> {
+        let mut a_member = ctx.accounts.member.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+        require!(
+            permissions & !ALL_PERMISSIONS == 0, 
+            NewsError::InvalidPermissions
+        );
+        // Only permissions the granting admin holds can be handed out or taken away.
         require!(
-            (a_user.count as usize) < MAX_REPORTER_COUNT, 
-            NewsError::OverMaxCount
+            permissions & !a_admin_member.permissions == 0, 
+            NewsError::MissingPermission
+        );
+        require!(
+            a_member.permissions & !a_admin_member.permissions == 0, 
+            NewsError::MissingPermission
+        );
+        // Under multisig, admin-level grants go through execute_edit_reporter instead.
+        require!(
+            ctx.accounts.config.load()?.multisig == Pubkey::default() || 
+            permissions & !a_member.permissions & PROPOSAL_PERMISSIONS == 0, 
+            NewsError::MultisigRequired
         );
 
-        let result: bool = a_user.add_reporter(a_admin.to_account_info().key(), 2)?;
-        require!(result, NewsError::CreateReporterError);
+        a_member.permissions = permissions;
 
-        a_user.count += 1;
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_EDIT_REPORTER, 
+            ctx.accounts.reporter.key(), 
+            permissions as u64
+        )?;
 
         Ok(())
     }
 
-    pub fn create_senior(
-        ctx: Context<CreateSeniorContext>
+    pub fn delete_reporter(
+        ctx: Context<DeleteReporterContext>
     ) -> Result<()> {
-        let mut a_user = ctx.accounts.user.load_mut()?;
         let a_admin = &ctx.accounts.admin;
-        let a_senior = &ctx.accounts.senior;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
 
+        // An admin cannot remove a member holding permissions they lack.
         require!(
-            (a_user.count as usize) < MAX_REPORTER_COUNT, 
-            NewsError::OverMaxCount
+            ctx.accounts.member.load()?.permissions & !a_admin_member.permissions == 0, 
+            NewsError::MissingPermission
         );
 
-        let is_admin: bool = a_user.validate_reporter(a_admin.to_account_info().key(), 2)?;
-        require!( is_admin,  NewsError::NotAdmin );
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_admin.key(), 
+            AUDIT_DELETE_REPORTER, 
+            ctx.accounts.reporter.key(), 
+            0
+        )?;
+
+        ctx.accounts.member.close(a_admin.to_account_info())?;
+
+        Ok(())
+    }
 
-        let result: bool = a_user.add_reporter(a_senior.to_account_info().key(), 1)?;
-        require!(result, NewsError::CreateReporterError);
+    pub fn suspend_reporter(
+        ctx: Context<SuspendReporterContext>,
+        reason: u32
+    ) -> Result<()> {
+        let mut a_member = ctx.accounts.member.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+        let a_admin = &ctx.accounts.admin;
 
-        a_user.count += 1;
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+        a_member.validate_active()?;
+
+        // An admin cannot suspend a member holding permissions they lack.
+        require!(
+            a_member.permissions & !a_admin_member.permissions == 0, 
+            NewsError::MissingPermission
+        );
+
+        a_member.status = SUSPENDED_STATUS;
+        a_member.status_reason = reason;
+        a_member.status_updated_by = a_admin.key();
+        a_member.status_updated_at = get_current_time()?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_admin.key(), 
+            AUDIT_SUSPEND_REPORTER, 
+            ctx.accounts.reporter.key(), 
+            reason as u64
+        )?;
 
         Ok(())
     }
 
-    pub fn edit_reporter(
-        ctx: Context<EditReporterContext>,
-        role: u32
+    pub fn reinstate_reporter(
+        ctx: Context<ReinstateReporterContext>,
+        reason: u32
+    ) -> Result<()> {
+        let mut a_member = ctx.accounts.member.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+        let a_admin = &ctx.accounts.admin;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+        require!(
+            a_member.status == SUSPENDED_STATUS, 
+            NewsError::ReporterNotSuspended
+        );
+
+        a_member.status = ACTIVE_STATUS;
+        a_member.status_reason = reason;
+        a_member.status_updated_by = a_admin.key();
+        a_member.status_updated_at = get_current_time()?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_admin.key(), 
+            AUDIT_REINSTATE_REPORTER, 
+            ctx.accounts.reporter.key(), 
+            reason as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn renew_role(
+        ctx: Context<RenewRoleContext>,
+        expires_at: u32
+    ) -> Result<()> {
+        let mut a_member = ctx.accounts.member.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+        validate_expires_at(expires_at)?;
+
+        // An admin cannot extend a member holding permissions they lack.
+        require!(
+            a_member.permissions & !a_admin_member.permissions == 0, 
+            NewsError::MissingPermission
+        );
+
+        a_member.expires_at = expires_at;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_RENEW_ROLE, 
+            ctx.accounts.reporter.key(), 
+            expires_at as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn prune_expired_role(
+        ctx: Context<PruneExpiredRoleContext>
+    ) -> Result<()> {
+        let a_cranker = &ctx.accounts.cranker;
+
+        {
+            let a_member = ctx.accounts.member.load()?;
+
+            require!(
+                a_member.is_expired(get_current_time()?), 
+                NewsError::RoleNotExpired
+            );
+            // Closing the Member lifts its payout hold, so a cranker must not prune a suspended one.
+            a_member.validate_active()?;
+        }
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_cranker.key(), 
+            AUDIT_PRUNE_EXPIRED_ROLE, 
+            ctx.accounts.reporter.key(), 
+            0
+        )?;
+
+        ctx.accounts.member.close(a_cranker.to_account_info())?;
+
+        Ok(())
+    }
+
+    pub fn migrate_reporter(
+        ctx: Context<MigrateReporterContext>
     ) -> Result<()> {
         let mut a_user = ctx.accounts.user.load_mut()?;
-        let a_old_reporter = &ctx.accounts.old_reporter;
+        let mut a_member = ctx.accounts.member.load_init()?;
         let a_reporter = &ctx.accounts.reporter;
 
-        let result: bool = a_user.edit_reporter(a_old_reporter.to_account_info().key(), a_reporter.to_account_info().key(), role)?;
-        require!(result, NewsError::EditReporterError);
+        migrate_legacy_reporter(&mut a_user, &mut a_member, ctx.accounts.user.key(), a_reporter.key())?;
+
+        // Under multisig, legacy admins are migrated through execute_migrate_reporter instead.
+        require!(
+            ctx.accounts.config.load()?.multisig == Pubkey::default() || 
+            a_member.permissions & PROPOSAL_PERMISSIONS == 0, 
+            NewsError::MultisigRequired
+        );
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_MIGRATE_REPORTER, 
+            a_reporter.key(), 
+            0
+        )?;
 
         Ok(())
     }
 
-    pub fn delete_reporter(
-        ctx: Context<DeleteReporterContext>
+    pub fn apply_as_reporter(
+        ctx: Context<ApplyAsReporterContext>
+    ) -> Result<()> {
+        let mut a_application = ctx.accounts.application.load_init()?;
+        let a_user = &ctx.accounts.user;
+        let a_applicant = &ctx.accounts.applicant;
+
+        a_application.registry = a_user.key();
+        a_application.applicant = a_applicant.to_account_info().key();
+        a_application.applied_at = get_current_time()?;
+
+        Ok(())
+    }
+
+    pub fn create_profile(
+        ctx: Context<CreateProfileContext>,
+        name_hash: [u8; 32],
+        uri: String
+    ) -> Result<()> {
+        let mut a_profile = ctx.accounts.profile.load_init()?;
+        let a_member = ctx.accounts.member.load()?;
+        let a_reporter = &ctx.accounts.reporter;
+
+        a_member.validate_active()?;
+
+        a_profile.reporter = a_reporter.to_account_info().key();
+        a_profile.name_hash = name_hash;
+        a_profile.joined_at = a_member.joined_at;
+        a_profile.set_uri(&uri)?;
+
+        Ok(())
+    }
+
+    pub fn update_profile(
+        ctx: Context<UpdateProfileContext>,
+        name_hash: [u8; 32],
+        uri: String
+    ) -> Result<()> {
+        let mut a_profile = ctx.accounts.profile.load_mut()?;
+
+        a_profile.name_hash = name_hash;
+        a_profile.set_uri(&uri)?;
+
+        Ok(())
+    }
+
+    pub fn accept_application(
+        ctx: Context<AcceptApplicationContext>
+    ) -> Result<()> {
+        {
+            let mut a_member = ctx.accounts.member.load_init()?;
+            let a_admin_member = ctx.accounts.admin_member.load()?;
+            let a_user = &ctx.accounts.user;
+            let a_applicant = &ctx.accounts.applicant;
+
+            a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+            a_member.registry = a_user.key();
+            a_member.reporter = a_applicant.to_account_info().key();
+            a_member.permissions = JUNIOR_PERMISSIONS;
+            a_member.status = ACTIVE_STATUS;
+            a_member.joined_at = get_current_time()?;
+        }
+
+        ctx.accounts.application.close(ctx.accounts.applicant.to_account_info())?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_ACCEPT_APPLICATION, 
+            ctx.accounts.applicant.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn reject_application(
+        ctx: Context<RejectApplicationContext>
+    ) -> Result<()> {
+        {
+            let a_admin_member = ctx.accounts.admin_member.load()?;
+
+            a_admin_member.validate_permission(ROLE_PERMISSION)?;
+        }
+
+        ctx.accounts.application.close(ctx.accounts.applicant.to_account_info())?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_REJECT_APPLICATION, 
+            ctx.accounts.applicant.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisigContext>,
+        signers: Vec<Pubkey>,
+        threshold: u32
+    ) -> Result<()> {
+        let mut a_multisig = ctx.accounts.multisig.load_init()?;
+        let mut a_config = ctx.accounts.config.load_mut()?;
+
+        a_multisig.set_signers(&signers, threshold)?;
+        a_config.multisig = ctx.accounts.multisig.key();
+        // A transfer proposed by the owner alone must not survive the switch to multisig.
+        a_config.pending_owner = Pubkey::default();
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_SET_SIGNERS, 
+            ctx.accounts.multisig.key(), 
+            threshold as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposalContext>,
+        action: ProposalAction
+    ) -> Result<()> {
+        let mut a_proposal = ctx.accounts.proposal.load_init()?;
+        let mut a_multisig = ctx.accounts.multisig.load_mut()?;
+        let a_proposer = &ctx.accounts.proposer;
+
+        a_proposal.multisig = ctx.accounts.multisig.key();
+        a_proposal.proposer = a_proposer.to_account_info().key();
+        a_proposal.index = a_multisig.proposal_count;
+        a_proposal.generation = a_multisig.generation;
+        a_proposal.created_at = get_current_time()?;
+        a_proposal.set_action(&action)?;
+        a_proposal.approve(&a_multisig, a_proposer.to_account_info().key())?;
+
+        a_multisig.proposal_count += 1;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_proposer.key(), 
+            AUDIT_CREATE_PROPOSAL, 
+            ctx.accounts.proposal.key(), 
+            a_proposal.index as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn approve_proposal(
+        ctx: Context<ApproveProposalContext>
+    ) -> Result<()> {
+        let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+        let a_multisig = ctx.accounts.multisig.load()?;
+        let a_signer = &ctx.accounts.signer;
+
+        a_proposal.approve(&a_multisig, a_signer.to_account_info().key())?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_signer.key(), 
+            AUDIT_APPROVE_PROPOSAL, 
+            ctx.accounts.proposal.key(), 
+            a_proposal.approvals.count_ones() as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_withdraw(
+        ctx: Context<ExecuteWithdrawContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let amount = match action {
+            ProposalAction::Withdraw { amount } => amount,
+            ProposalAction::WithdrawAll => ctx.accounts.owner_vault.load()?.balance,
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        withdraw_from_owner_vault(&ctx.accounts.owner_vault, &ctx.accounts.owner, amount)?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_WITHDRAW, 
+            ctx.accounts.owner_vault.key(), 
+            amount
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_edit_vault(
+        ctx: Context<ExecuteEditVaultContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let balance = match action {
+            ProposalAction::EditVault { vault, balance } if vault == ctx.accounts.vault.key() => balance,
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        let mut a_vault = ctx.accounts.vault.load_mut()?;
+        a_vault.balance = balance;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_EDIT_VAULT, 
+            ctx.accounts.vault.key(), 
+            balance
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_create_admin(
+        ctx: Context<ExecuteCreateAdminContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let expires_at = match action {
+            ProposalAction::CreateAdmin { admin, expires_at } if admin == ctx.accounts.admin.key() => expires_at,
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        validate_expires_at(expires_at)?;
+
+        let mut a_admin_member = ctx.accounts.admin_member.load_init()?;
+        let a_user = &ctx.accounts.user;
+        let a_admin = &ctx.accounts.admin;
+
+        a_admin_member.registry = a_user.key();
+        a_admin_member.reporter = a_admin.to_account_info().key();
+        a_admin_member.permissions = ADMIN_PERMISSIONS;
+        a_admin_member.status = ACTIVE_STATUS;
+        a_admin_member.joined_at = get_current_time()?;
+        a_admin_member.expires_at = expires_at;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_CREATE_ADMIN, 
+            a_admin.key(), 
+            expires_at as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_set_signers(
+        ctx: Context<ExecuteSetSignersContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let (signers, threshold) = match action {
+            ProposalAction::SetSigners { signers, threshold } => (signers, threshold),
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        let mut a_multisig = ctx.accounts.multisig.load_mut()?;
+        a_multisig.set_signers(&signers, threshold)?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_SET_SIGNERS, 
+            ctx.accounts.multisig.key(), 
+            threshold as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_propose_owner(
+        ctx: Context<ExecuteProposeOwnerContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let new_owner = match action {
+            ProposalAction::ProposeOwner { new_owner } => new_owner,
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        let mut a_config = ctx.accounts.config.load_mut()?;
+        // accept_owner hands over the owner vault, so one has to exist before a transfer starts.
+        require!(
+            new_owner == Pubkey::default() || a_config.owner_vault != Pubkey::default(), 
+            NewsError::OwnerVaultNotCreated
+        );
+        a_config.pending_owner = new_owner;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_PROPOSE_OWNER, 
+            new_owner, 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_update_config(
+        ctx: Context<ExecuteUpdateConfigContext>
     ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+
+        let mut a_config = ctx.accounts.config.load_mut()?;
+        let (audit_action, amount) = match action {
+            ProposalAction::UpdateConfig { news_payout, campaign_fee, campaign_vault } => {
+                a_config.news_payout = news_payout;
+                a_config.campaign_fee = campaign_fee;
+                a_config.campaign_vault = campaign_vault;
+                (AUDIT_UPDATE_CONFIG, news_payout)
+            },
+            ProposalAction::UpdateReviewPolicy { approval_quorum, deny_policy, max_resubmissions, review_deadline } => {
+                a_config.set_review_policy(approval_quorum, deny_policy, max_resubmissions, review_deadline)?;
+                (AUDIT_UPDATE_REVIEW_POLICY, approval_quorum as u64)
+            },
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            audit_action, 
+            ctx.accounts.config.key(), 
+            amount
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_edit_reporter(
+        ctx: Context<ExecuteEditReporterContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        let permissions = match action {
+            ProposalAction::EditReporter { reporter, permissions } if reporter == ctx.accounts.reporter.key() => permissions,
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
+        require!(
+            permissions & !ALL_PERMISSIONS == 0, 
+            NewsError::InvalidPermissions
+        );
+
+        let mut a_member = ctx.accounts.member.load_mut()?;
+        a_member.permissions = permissions;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_EDIT_REPORTER, 
+            ctx.accounts.reporter.key(), 
+            permissions as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_migrate_reporter(
+        ctx: Context<ExecuteMigrateReporterContext>
+    ) -> Result<()> {
+        let action = {
+            let mut a_proposal = ctx.accounts.proposal.load_mut()?;
+            let a_multisig = ctx.accounts.multisig.load()?;
+
+            a_proposal.execute(&a_multisig)?
+        };
+        match action {
+            ProposalAction::MigrateReporter { reporter } if reporter == ctx.accounts.reporter.key() => {},
+            _ => return err!(NewsError::InvalidProposalAction)
+        };
+
         let mut a_user = ctx.accounts.user.load_mut()?;
+        let mut a_member = ctx.accounts.member.load_init()?;
         let a_reporter = &ctx.accounts.reporter;
 
-        let result: bool = a_user.delete_reporter(a_reporter.to_account_info().key())?;
-        require!(result, NewsError::DeleteReporterError);
-        a_user.count -= 1;
+        migrate_legacy_reporter(&mut a_user, &mut a_member, ctx.accounts.user.key(), a_reporter.key())?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.executor.key(), 
+            AUDIT_MIGRATE_REPORTER, 
+            a_reporter.key(), 
+            0
+        )?;
 
         Ok(())
     }
@@ -210,35 +986,316 @@ pub mod news {
         let mut a_vault = ctx.accounts.vault.load_mut()?;
 
         a_vault.balance = price;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_EDIT_VAULT, 
+            ctx.accounts.vault.key(), 
+            price
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_category(
+        ctx: Context<CreateCategoryContext>,
+        category_id: u32,
+        name_hash: [u8; 32],
+        approval_quorum: u32
+    ) -> Result<()> {
+        let mut a_category = ctx.accounts.category.load_init()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+        a_category.category_id = category_id;
+        a_category.name_hash = name_hash;
+        a_category.approval_quorum = approval_quorum;
+        a_category.created_by = ctx.accounts.admin.key();
+        a_category.created_at = get_current_time()?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_CREATE_CATEGORY, 
+            ctx.accounts.category.key(), 
+            approval_quorum as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn edit_category(
+        ctx: Context<EditCategoryContext>,
+        name_hash: [u8; 32],
+        approval_quorum: u32
+    ) -> Result<()> {
+        let mut a_category = ctx.accounts.category.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+        a_category.name_hash = name_hash;
+        a_category.approval_quorum = approval_quorum;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_EDIT_CATEGORY, 
+            ctx.accounts.category.key(), 
+            approval_quorum as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn grant_category_editor(
+        ctx: Context<GrantCategoryEditorContext>
+    ) -> Result<()> {
+        let mut a_category_editor = ctx.accounts.category_editor.load_init()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(ROLE_PERMISSION)?;
+
+        a_category_editor.category = ctx.accounts.category.key();
+        a_category_editor.editor = ctx.accounts.editor.key();
+        a_category_editor.granted_by = ctx.accounts.admin.key();
+        a_category_editor.granted_at = get_current_time()?;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_GRANT_CATEGORY_EDITOR, 
+            ctx.accounts.editor.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn revoke_category_editor(
+        ctx: Context<RevokeCategoryEditorContext>
+    ) -> Result<()> {
+        let a_admin = &ctx.accounts.admin;
+
+        {
+            let a_admin_member = ctx.accounts.admin_member.load()?;
+
+            a_admin_member.validate_permission(ROLE_PERMISSION)?;
+        }
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            a_admin.key(), 
+            AUDIT_REVOKE_CATEGORY_EDITOR, 
+            ctx.accounts.editor.key(), 
+            0
+        )?;
+
+        ctx.accounts.category_editor.close(a_admin.to_account_info())?;
+
+        Ok(())
+    }
+
+    pub fn create_news_registry(
+        ctx: Context<CreateNewsRegistryContext>
+    ) -> Result<()> {
+        let mut a_news_registry = ctx.accounts.news_registry.load_init()?;
+
+        a_news_registry.count = 0;
+        a_news_registry.page_count = 0;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.owner.key(), 
+            AUDIT_CREATE_NEWS_REGISTRY, 
+            ctx.accounts.news_registry.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_index_page(
+        ctx: Context<CreateIndexPageContext>
+    ) -> Result<()> {
+        let mut a_index_page = ctx.accounts.index_page.load_init()?;
+        let mut a_news_registry = ctx.accounts.news_registry.load_mut()?;
+
+        a_index_page.page = a_news_registry.page_count;
+        a_news_registry.page_count += 1;
+
+        Ok(())
+    }
+
+    pub fn sync_index_entry(
+        ctx: Context<SyncIndexEntryContext>
+    ) -> Result<()> {
+        let a_pool = ctx.accounts.pool.load()?;
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
         Ok(())
     }
 
     pub fn create_news(
         ctx: Context<CreateNewsContext>, 
         news_id: u64,
+        content_hash: [u8; 32],
+        uri: String,
+        tags: Vec<u32>
     ) -> Result<()> {
         let mut a_pool = ctx.accounts.pool.load_init()?;
+        let a_member = ctx.accounts.member.load()?;
         let a_reporter = &ctx.accounts.reporter;
         let current_time = get_current_time()?;
 
+        a_member.validate_permission(SUBMIT_PERMISSION)?;
+
         a_pool.news_id = news_id;
         a_pool.reporter = a_reporter.to_account_info().key();
         a_pool.created_at = current_time;
         a_pool.updated_at = current_time;
-        a_pool.state = 0;
+        a_pool.state = NewsState::Draft as u32;
+        a_pool.content_hash = content_hash;
+        a_pool.set_uri(&uri)?;
+        a_pool.revision = FIRST_REVISION;
+        a_pool.category = ctx.accounts.category.key();
+        a_pool.set_tags(&tags)?;
+
+        let mut a_news_registry = ctx.accounts.news_registry.load_mut()?;
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_pool.sequence = a_news_registry.count;
+        a_index_page.push(a_pool.sequence, ctx.accounts.pool.key(), a_pool.state);
+        a_news_registry.count += 1;
+
+        let mut a_revision = ctx.accounts.revision.load_init()?;
+        a_revision.pool = ctx.accounts.pool.key();
+        a_revision.editor = a_reporter.to_account_info().key();
+        a_revision.content_hash = content_hash;
+        a_revision.revision = FIRST_REVISION;
+        a_revision.created_at = current_time;
+
+        let mut a_profile = ctx.accounts.profile.load_mut()?;
+        a_profile.submitted_count += 1;
         Ok(())
     }
 
     pub fn edit_news(
         ctx: Context<EditNewsContext>, 
         news_id: u64, 
+        content_hash: [u8; 32],
+        uri: String
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_member = ctx.accounts.member.load()?;
+        let current_time = get_current_time()?;
+
+        a_member.validate_permission(SUBMIT_PERMISSION)?;
+
+        // Denied articles come back through resubmit_news, which counts the attempt.
+        require!(
+            a_pool.news_state()? != NewsState::Denied, 
+            NewsError::NewsDenied
+        );
+
+        a_pool.transition(NewsState::Edited)?;
+        a_pool.news_id = news_id;
+        a_pool.updated_at = current_time;
+        a_pool.content_hash = content_hash;
+        a_pool.set_uri(&uri)?;
+        a_pool.revision += 1;
+        // Any approval or escalation was for the previous content and no longer applies.
+        a_pool.reset_approvals();
+        a_pool.escalated_at = 0;
+        a_pool.review_due_at = 0;
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        let mut a_revision = ctx.accounts.revision.load_init()?;
+        a_revision.pool = ctx.accounts.pool.key();
+        a_revision.editor = ctx.accounts.reporter.key();
+        a_revision.content_hash = content_hash;
+        a_revision.revision = a_pool.revision;
+        a_revision.created_at = current_time;
+        Ok(())
+    }
+
+    pub fn resubmit_news(
+        ctx: Context<ResubmitNewsContext>, 
+        news_id: u64, 
+        content_hash: [u8; 32],
+        uri: String
     ) -> Result<()> {
         let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_member = ctx.accounts.member.load()?;
         let current_time = get_current_time()?;
 
+        a_member.validate_permission(SUBMIT_PERMISSION)?;
+
+        require!(
+            a_pool.news_state()? == NewsState::Denied, 
+            NewsError::InvalidStateTransition
+        );
+        require!(
+            a_pool.resubmit_count < ctx.accounts.config.load()?.max_resubmissions, 
+            NewsError::ResubmitLimitReached
+        );
+
+        a_pool.transition(NewsState::Edited)?;
+        a_pool.resubmit_count += 1;
         a_pool.news_id = news_id;
         a_pool.updated_at = current_time;
-        a_pool.state = 1;
+        a_pool.content_hash = content_hash;
+        a_pool.set_uri(&uri)?;
+        a_pool.revision += 1;
+        a_pool.reset_approvals();
+        a_pool.escalated_at = 0;
+        a_pool.review_due_at = 0;
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        let mut a_revision = ctx.accounts.revision.load_init()?;
+        a_revision.pool = ctx.accounts.pool.key();
+        a_revision.editor = ctx.accounts.reporter.key();
+        a_revision.content_hash = content_hash;
+        a_revision.revision = a_pool.revision;
+        a_revision.created_at = current_time;
+        Ok(())
+    }
+
+    pub fn set_coauthors(
+        ctx: Context<SetCoAuthorsContext>,
+        authors: Vec<Pubkey>,
+        shares: Vec<u32>
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+
+        // The split is part of what reviewers approve, so it is fixed once review has finished.
+        require!(
+            matches!(a_pool.news_state()?, NewsState::Draft | NewsState::Edited), 
+            NewsError::InvalidStateTransition
+        );
+
+        a_pool.set_coauthors(&authors, &shares)?;
+        a_pool.reset_approvals();
+
+        Ok(())
+    }
+
+    pub fn accept_coauthorship(
+        ctx: Context<AcceptCoAuthorshipContext>
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_member = ctx.accounts.member.load()?;
+
+        a_member.validate_permission(SUBMIT_PERMISSION)?;
+        a_pool.accept_coauthorship(ctx.accounts.coauthor.key())?;
+
         Ok(())
     }
 
@@ -247,74 +1304,403 @@ pub mod news {
     ) -> Result<()> {
         let a_reporter = &ctx.accounts.reporter;
 
+        {
+            let a_pool = ctx.accounts.pool.load()?;
+            let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+
+            a_index_page.set_state(a_pool.sequence, DELETED_INDEX_STATE);
+        }
+
         ctx.accounts.pool.close(a_reporter.to_account_info())?;
         
         Ok(())
     }
 
     pub fn approve_news(
-        ctx: Context<ApproveNewsContext>
+        ctx: Context<ApproveNewsContext>,
+        content_hash: [u8; 32],
+        revision: u32
     ) -> Result<()> {
         let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_senior_member = ctx.accounts.senior_member.load()?;
 
-        let a_user = ctx.accounts.user.load_mut()?;
-        let a_senior = &ctx.accounts.senior;
+        a_senior_member.validate_permission(REVIEW_PERMISSION)?;
+        a_pool.validate_reviewer(ctx.accounts.senior.key())?;
+        validate_category_reviewer(&a_pool, &a_senior_member, &ctx.accounts.category_editor)?;
 
-        let is_senior: bool = a_user.validate_reporter(a_senior.to_account_info().key(), 1)?;
-        require!( is_senior,  NewsError::NotSeniorReporter);
+        require!(
+            a_pool.content_hash == content_hash, 
+            NewsError::ContentHashMismatch
+        );
+        require!(
+            a_pool.revision == revision, 
+            NewsError::RevisionMismatch
+        );
+
+        require!(
+            a_pool.news_state()?.can_transition_to(NewsState::Approved), 
+            NewsError::InvalidStateTransition
+        );
+
+        let mut a_approval = ctx.accounts.approval.load_init()?;
+        a_approval.pool = ctx.accounts.pool.key();
+        a_approval.reviewer = ctx.accounts.senior.key();
+        a_approval.round = a_pool.approval_round;
+        a_approval.approved_at = get_current_time()?;
+
+        a_pool.approval_count += 1;
+
+        let quorum = ctx.accounts.category.load()?.approval_quorum(&*ctx.accounts.config.load()?);
+
+        if a_pool.approval_count >= quorum {
+            a_pool.transition(NewsState::Approved)?;
+            a_pool.approved_hash = content_hash;
+            a_pool.approved_revision = revision;
+
+            let mut a_profile = ctx.accounts.profile.load_mut()?;
+            a_profile.approved_count += 1;
+        }
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.senior.key(), 
+            AUDIT_APPROVE_NEWS, 
+            ctx.accounts.pool.key(), 
+            0
+        )?;
 
-        a_pool.state = 2;
         Ok(())
     }
 
     pub fn deny_news(
-        ctx: Context<DenyNewsContext>
+        ctx: Context<DenyNewsContext>,
+        reason: u32,
+        feedback_hash: [u8; 32]
     ) -> Result<()> {
         let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_senior_member = ctx.accounts.senior_member.load()?;
 
-        let a_user = ctx.accounts.user.load_mut()?;
-        let a_senior = &ctx.accounts.senior;
+        a_senior_member.validate_permission(REVIEW_PERMISSION)?;
+        a_pool.validate_reviewer(ctx.accounts.senior.key())?;
+        validate_category_reviewer(&a_pool, &a_senior_member, &ctx.accounts.category_editor)?;
+
+        a_pool.deny_reason = reason;
+        a_pool.feedback_hash = feedback_hash;
+
+        if ctx.accounts.config.load()?.deny_policy == DENY_RESETS_POLICY {
+            // The article stays in review, but the approvals collected so far are discarded.
+            require!(
+                a_pool.news_state()?.can_transition_to(NewsState::Denied), 
+                NewsError::InvalidStateTransition
+            );
+
+            a_pool.reset_approvals();
+        } else {
+            a_pool.transition(NewsState::Denied)?;
+
+            let mut a_profile = ctx.accounts.profile.load_mut()?;
+            a_profile.denied_count += 1;
+        }
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.senior.key(), 
+            AUDIT_DENY_NEWS, 
+            ctx.accounts.pool.key(), 
+            reason as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn escalate_review(
+        ctx: Context<EscalateReviewContext>
+    ) -> Result<()> {
+        let current_time = get_current_time()?;
+
+        {
+            let mut a_pool = ctx.accounts.pool.load_mut()?;
+            let review_deadline = ctx.accounts.config.load()?.review_deadline;
+
+            require!(
+                matches!(a_pool.news_state()?, NewsState::Draft | NewsState::Edited), 
+                NewsError::InvalidStateTransition
+            );
+            require!(a_pool.escalated_at == 0, NewsError::AlreadyEscalated);
+            require!(
+                review_deadline != 0 && 
+                current_time as u64 >= a_pool.updated_at as u64 + review_deadline as u64, 
+                NewsError::ReviewNotOverdue
+            );
+
+            a_pool.escalated_at = current_time;
+            a_pool.review_due_at = a_pool.updated_at.saturating_add(review_deadline);
+        }
+
+        let mut a_category = ctx.accounts.category.load_mut()?;
+        a_category.escalation_count += 1;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.cranker.key(), 
+            AUDIT_ESCALATE_REVIEW, 
+            ctx.accounts.pool.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn count_editor_escalation(
+        ctx: Context<CountEditorEscalationContext>
+    ) -> Result<()> {
+        let a_pool = ctx.accounts.pool.load()?;
+        let mut a_category_editor = ctx.accounts.category_editor.load_mut()?;
 
-        let is_senior: bool = a_user.validate_reporter(a_senior.to_account_info().key(), 1)?;
-        require!( is_senior,  NewsError::NotSeniorReporter);
+        // Editors granted after the deadline passed were never responsible for the review.
+        require!(
+            a_category_editor.granted_at <= a_pool.review_due_at, 
+            NewsError::EditorGrantedAfterDeadline
+        );
+
+        a_category_editor.escalation_count += 1;
+
+        let mut a_escalation = ctx.accounts.escalation.load_init()?;
+        a_escalation.pool = ctx.accounts.pool.key();
+        a_escalation.category_editor = ctx.accounts.category_editor.key();
+        a_escalation.escalated_at = a_pool.escalated_at;
+        a_escalation.counted_at = get_current_time()?;
 
-        a_pool.state = 3;
         Ok(())
     }
 
     pub fn publish_news(ctx: Context<PublishNewsContext>) -> Result<()> {
         
         let mut a_pool = ctx.accounts.pool.load_mut()?;
-        let mut a_vault = ctx.accounts.vault.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
 
-        let a_user = ctx.accounts.user.load_mut()?;
-        let a_admin = &ctx.accounts.admin;
+        a_admin_member.validate_permission(PUBLISH_PERMISSION)?;
+        a_pool.validate_reviewer(ctx.accounts.admin.key())?;
+
+        let news_payout = ctx.accounts.config.load()?.news_payout;
+
+        a_pool.publish(get_current_time()?, news_payout)?;
+
+        credit_author_vaults(
+            &a_pool, 
+            &ctx.accounts.vault.to_account_info(), 
+            ctx.remaining_accounts, 
+            news_payout
+        )?;
+
+        let mut a_profile = ctx.accounts.profile.load_mut()?;
+        a_profile.published_count += 1;
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_PUBLISH_NEWS, 
+            ctx.accounts.pool.key(), 
+            news_payout
+        )?;
+
+        Ok(())
+    }
 
-        let is_admin: bool = a_user.validate_reporter(a_admin.to_account_info().key(), 2)?;
-        require!( is_admin,  NewsError::NotAdmin);
+    pub fn retract_news(
+        ctx: Context<RetractNewsContext>,
+        reason: u32,
+        reverse_payout: bool
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(PUBLISH_PERMISSION)?;
+
+        a_pool.transition(NewsState::Retracted)?;
+        a_pool.retract_reason = reason;
+        a_pool.retracted_at = get_current_time()?;
+
+        // Only the part of the publish credit that has not been paid out yet can be taken back.
+        let reversed = if reverse_payout {
+            debit_author_vaults(
+                &a_pool, 
+                &ctx.accounts.vault.to_account_info(), 
+                ctx.remaining_accounts, 
+                a_pool.payout
+            )?
+        } else {
+            0
+        };
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_RETRACT_NEWS, 
+            ctx.accounts.pool.key(), 
+            reversed
+        )?;
+
+        Ok(())
+    }
+
+    pub fn issue_correction(
+        ctx: Context<IssueCorrectionContext>,
+        content_hash: [u8; 32]
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let mut a_correction = ctx.accounts.correction.load_init()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(PUBLISH_PERMISSION)?;
+
+        require!(
+            matches!(a_pool.news_state()?, NewsState::Published | NewsState::Retracted), 
+            NewsError::NotPublishedNews
+        );
+
+        a_correction.pool = ctx.accounts.pool.key();
+        a_correction.issuer = ctx.accounts.admin.key();
+        a_correction.content_hash = content_hash;
+        a_correction.index = a_pool.correction_count;
+        a_correction.created_at = get_current_time()?;
+
+        a_pool.correction_count += 1;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_ISSUE_CORRECTION, 
+            ctx.accounts.correction.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn schedule_publish(
+        ctx: Context<SchedulePublishContext>,
+        publish_at: u32
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(PUBLISH_PERMISSION)?;
+        a_pool.validate_reviewer(ctx.accounts.admin.key())?;
 
         require!(
-            a_pool.state == 2, 
+            a_pool.news_state()? == NewsState::Approved, 
             NewsError::NotApprovedNews
         );
-        
-        a_pool.state = 4;
-        a_vault.balance += FIXED_SOL;
+        require!(
+            publish_at > get_current_time()?, 
+            NewsError::InvalidPublishTime
+        );
+
+        // Calling this again on a scheduled article reschedules it.
+        a_pool.publish_at = publish_at;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_SCHEDULE_PUBLISH, 
+            ctx.accounts.pool.key(), 
+            publish_at as u64
+        )?;
+
+        Ok(())
+    }
+
+    pub fn cancel_scheduled_publish(
+        ctx: Context<CancelScheduledPublishContext>
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let a_admin_member = ctx.accounts.admin_member.load()?;
+
+        a_admin_member.validate_permission(PUBLISH_PERMISSION)?;
+
+        require!(a_pool.publish_at != 0, NewsError::NewsNotScheduled);
+
+        a_pool.publish_at = 0;
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_CANCEL_SCHEDULED_PUBLISH, 
+            ctx.accounts.pool.key(), 
+            0
+        )?;
+
+        Ok(())
+    }
+
+    pub fn execute_scheduled_publish(
+        ctx: Context<ExecuteScheduledPublishContext>
+    ) -> Result<()> {
+        let mut a_pool = ctx.accounts.pool.load_mut()?;
+        let current_time = get_current_time()?;
+
+        require!(a_pool.publish_at != 0, NewsError::NewsNotScheduled);
+        require!(
+            current_time >= a_pool.publish_at, 
+            NewsError::PublishTimeNotReached
+        );
+
+        let news_payout = ctx.accounts.config.load()?.news_payout;
+
+        a_pool.publish(current_time, news_payout)?;
+
+        credit_author_vaults(
+            &a_pool, 
+            &ctx.accounts.vault.to_account_info(), 
+            ctx.remaining_accounts, 
+            news_payout
+        )?;
+
+        let mut a_profile = ctx.accounts.profile.load_mut()?;
+        a_profile.published_count += 1;
+
+        let mut a_index_page = ctx.accounts.index_page.load_mut()?;
+        a_index_page.set_state(a_pool.sequence, a_pool.state);
+
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.cranker.key(), 
+            AUDIT_PUBLISH_NEWS, 
+            ctx.accounts.pool.key(), 
+            news_payout
+        )?;
 
         Ok(())
     }
 
     pub fn payout_junior(ctx: Context<PayoutJuniorContext>) -> Result<()> {
-        
+        let amount = ctx.accounts.vault.load()?.balance;
+
         {
             let owner_vault = ctx.accounts.owner_vault.load()?;
             let a_vault = ctx.accounts.vault.load()?;
+            let a_admin_member = ctx.accounts.admin_member.load()?;
 
-            let a_user = ctx.accounts.user.load_mut()?;
-            let a_admin = &ctx.accounts.admin;
+            // Paying out draws on the owner vault, so it needs treasury rights as well.
+            a_admin_member.validate_permission(PAYOUT_PERMISSION | TREASURY_PERMISSION)?;
 
-            let is_admin: bool = a_user.validate_reporter(a_admin.to_account_info().key(), 2)?;
-            require!( is_admin,  NewsError::NotAdmin);
+            // Suspension holds payouts back, but a removed or expired member keeps what they earned.
+            if let Ok(junior_member) = AccountLoader::<Member>::try_from(&ctx.accounts.junior_member) {
+                junior_member.load()?.validate_active()?;
+            }
 
             require!(
                 owner_vault.balance > 0 && owner_vault.balance - a_vault.balance > 0, 
@@ -340,9 +1726,20 @@ pub mod news {
             a_owner_vault.balance -= ctx.accounts.vault.load()?.balance;
 
             let mut a_vault = ctx.accounts.vault.load_mut()?;
-            a_vault.balance = 0
+            a_vault.balance = 0;
+
+            let mut a_profile = ctx.accounts.profile.load_mut()?;
+            a_profile.lifetime_earnings += amount;
         }
         
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_PAYOUT_JUNIOR, 
+            ctx.accounts.junior.key(), 
+            amount
+        )?;
+
         Ok(())
     }
 
@@ -392,6 +1789,7 @@ pub mod news {
         let system_program = &ctx.accounts.system_program;
 
         let a_advertiser = &ctx.accounts.advertiser;
+        let campaign_fee = ctx.accounts.config.load()?.campaign_fee;
         let current_time = get_current_time()?;
 
         a_campaign_pool.campaign_id = campaign_id;
@@ -399,6 +1797,7 @@ pub mod news {
         a_campaign_pool.created_at = current_time;
         a_campaign_pool.updated_at = current_time;
         a_campaign_pool.state = 0;
+        a_campaign_pool.fee = campaign_fee;
 
         let cpi_ctx = CpiContext::new(
             system_program.to_account_info(),
@@ -408,7 +1807,7 @@ pub mod news {
             }
         );
 
-        anchor_lang::system_program::transfer(cpi_ctx, FIXED_SOL)?;
+        anchor_lang::system_program::transfer(cpi_ctx, campaign_fee)?;
 
         Ok(())
     }
@@ -440,17 +1839,17 @@ pub mod news {
         ctx: Context<ApproveCampaignContext>
     ) -> Result<()> {
         {
-            let a_user = ctx.accounts.user.load_mut()?;
-            let a_admin = &ctx.accounts.admin;
+            let a_admin_member = ctx.accounts.admin_member.load()?;
     
-            let is_admin: bool = a_user.validate_reporter(a_admin.to_account_info().key(), 2)?;
-            require!( is_admin,  NewsError::NotAdmin);
+            a_admin_member.validate_permission(CAMPAIGN_PERMISSION)?;
 
             let mut a_campaign_pool = ctx.accounts.campaign_pool.load_mut()?;
+            a_campaign_pool.validate_reviewer(ctx.accounts.admin.key())?;
             a_campaign_pool.state = 2;
         }
         {
             let system_program = &ctx.accounts.system_program;
+            let fee = ctx.accounts.campaign_pool.load()?.fee;
 
              let cpi_ctx = CpiContext::new (
                 system_program.to_account_info(),
@@ -459,10 +1858,18 @@ pub mod news {
                     to: ctx.accounts.admin.to_account_info()
                 }
             );
-            anchor_lang::system_program::transfer(cpi_ctx, FIXED_SOL)?;
+            anchor_lang::system_program::transfer(cpi_ctx, fee)?;
 
         }
 
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_APPROVE_CAMPAIGN, 
+            ctx.accounts.campaign_pool.key(), 
+            ctx.accounts.campaign_pool.load()?.fee
+        )?;
+
         Ok(())
     }
 
@@ -470,17 +1877,17 @@ pub mod news {
         ctx: Context<DenyCampaignContext>
     ) -> Result<()> {
         {   
-            let a_user = ctx.accounts.user.load_mut()?;
-            let a_admin = &ctx.accounts.admin;
+            let a_admin_member = ctx.accounts.admin_member.load()?;
     
-            let is_admin: bool = a_user.validate_reporter(a_admin.to_account_info().key(), 2)?;
-            require!( is_admin,  NewsError::NotAdmin);
+            a_admin_member.validate_permission(CAMPAIGN_PERMISSION)?;
 
             let mut a_campaign_pool = ctx.accounts.campaign_pool.load_mut()?;
+            a_campaign_pool.validate_reviewer(ctx.accounts.admin.key())?;
             a_campaign_pool.state = 3;
         }
         {
             let system_program = &ctx.accounts.system_program;
+            let fee = ctx.accounts.campaign_pool.load()?.fee;
 
              let cpi_ctx = CpiContext::new (
                 system_program.to_account_info(),
@@ -489,10 +1896,18 @@ pub mod news {
                     to: ctx.accounts.advertiser.to_account_info()
                 }
             );
-            anchor_lang::system_program::transfer(cpi_ctx, FIXED_SOL)?;
+            anchor_lang::system_program::transfer(cpi_ctx, fee)?;
 
         }
 
+        append_audit_log(
+            &ctx.accounts.audit_log, 
+            ctx.accounts.admin.key(), 
+            AUDIT_DENY_CAMPAIGN, 
+            ctx.accounts.campaign_pool.key(), 
+            ctx.accounts.campaign_pool.load()?.fee
+        )?;
+
         Ok(())
     }
 }
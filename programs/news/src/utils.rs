@@ -1,7 +1,161 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{clock};
 
+use crate::account::*;
+use crate::constants::*;
+use crate::errors::*;
+
 pub fn get_current_time() -> Result<u32> {
   let clock = clock::Clock::get().unwrap();
   Ok(clock.unix_timestamp as u32)
 }
+
+pub fn validate_expires_at(expires_at: u32) -> Result<()> {
+  require!(
+    expires_at == 0 || expires_at > get_current_time()?,
+    NewsError::InvalidExpiry
+  );
+
+  Ok(())
+}
+
+// Moves a legacy User registry entry onto its Member account with the matching preset.
+pub fn migrate_legacy_reporter(
+  user: &mut User,
+  member: &mut Member,
+  registry: Pubkey,
+  reporter: Pubkey
+) -> Result<()> {
+  let index = match user.find_reporter(reporter) {
+    Some(index) => index,
+    None => return err!(NewsError::ReporterNotFound)
+  };
+
+  member.registry = registry;
+  member.reporter = reporter;
+  member.permissions = role_permissions(user.reporters[index].role);
+  member.status = ACTIVE_STATUS;
+  member.joined_at = get_current_time()?;
+
+  user.delete_reporter(reporter)
+}
+
+pub fn withdraw_from_owner_vault<'info>(
+  owner_vault: &AccountLoader<'info, OwnerVault>,
+  to: &AccountInfo<'info>,
+  amount: u64
+) -> Result<()> {
+  {
+    let a_owner_vault = owner_vault.load()?;
+    require!(
+      a_owner_vault.balance > 0 && amount <= a_owner_vault.balance,
+      NewsError::InsufficientFunds
+    );
+  }
+  {
+    let from = owner_vault.to_account_info();
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+  }
+  {
+    let mut a_owner_vault = owner_vault.load_mut()?;
+    a_owner_vault.balance -= amount;
+  }
+
+  Ok(())
+}
+
+pub fn append_audit_log(
+  audit_log: &AccountLoader<AuditLog>,
+  actor: Pubkey,
+  action: u32,
+  target: Pubkey,
+  amount: u64
+) -> Result<()> {
+  let mut a_audit_log = audit_log.load_mut()?;
+  a_audit_log.append(actor, action, target, amount, get_current_time()?);
+
+  Ok(())
+}
+
+// Single-author articles pay into `vault`; co-authored ones take one vault per
+// co-author, in list order, from the remaining accounts.
+pub fn credit_author_vaults(
+  pool: &Pool,
+  vault: &AccountInfo,
+  remaining_accounts: &[AccountInfo],
+  payout: u64
+) -> Result<()> {
+  let shares = pool.payout_shares(payout);
+
+  if pool.coauthor_count == 0 {
+    credit_vaults(std::slice::from_ref(vault), &shares)
+  } else {
+    credit_vaults(remaining_accounts, &shares)
+  }
+}
+
+// Reverses a credit made by credit_author_vaults and returns the amount taken back.
+pub fn debit_author_vaults(
+  pool: &Pool,
+  vault: &AccountInfo,
+  remaining_accounts: &[AccountInfo],
+  payout: u64
+) -> Result<u64> {
+  let shares = pool.payout_shares(payout);
+
+  if pool.coauthor_count == 0 {
+    debit_vaults(std::slice::from_ref(vault), &shares)
+  } else {
+    debit_vaults(remaining_accounts, &shares)
+  }
+}
+
+fn credit_vaults(vaults: &[AccountInfo], shares: &[(Pubkey, u64)]) -> Result<()> {
+  require!(vaults.len() == shares.len(), NewsError::InvalidVaults);
+
+  for (vault, (author, amount)) in vaults.iter().zip(shares) {
+    let loader = AccountLoader::<Vault>::try_from(vault)?;
+    let mut a_vault = loader.load_mut()?;
+
+    require!(a_vault.reporter == *author, NewsError::NotVaultReporter);
+    a_vault.balance += amount;
+  }
+
+  Ok(())
+}
+
+// Takes back each share, capped at what is still unpaid in the vault, and returns the total.
+fn debit_vaults(vaults: &[AccountInfo], shares: &[(Pubkey, u64)]) -> Result<u64> {
+  require!(vaults.len() == shares.len(), NewsError::InvalidVaults);
+
+  let mut total = 0;
+  for (vault, (author, amount)) in vaults.iter().zip(shares) {
+    let loader = AccountLoader::<Vault>::try_from(vault)?;
+    let mut a_vault = loader.load_mut()?;
+
+    require!(a_vault.reporter == *author, NewsError::NotVaultReporter);
+    let debit = (*amount).min(a_vault.balance);
+    a_vault.balance -= debit;
+    total += debit;
+  }
+
+  Ok(total)
+}
+
+// The reviewer needs an editor grant for the article's category; once the article is
+// escalated, any publisher can review it as well.
+pub fn validate_category_reviewer(
+  pool: &Pool,
+  member: &Member,
+  category_editor: &AccountInfo
+) -> Result<()> {
+  if AccountLoader::<CategoryEditor>::try_from(category_editor).is_ok() {
+    return Ok(());
+  }
+  if pool.escalated_at != 0 {
+    return member.validate_permission(PUBLISH_PERMISSION);
+  }
+
+  err!(NewsError::NotCategoryEditor)
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use proptest::prelude::*;
+
+use news::account::*;
+use news::constants::*;
+use news::errors::*;
+
+mod common;
+use common::*;
+
+fn pool(reporter: Pubkey) -> Pool {
+  Pool { reporter, ..Pool::default() }
+}
+
+#[test]
+fn set_coauthors_validates_lists_and_shares() {
+  let mut pool = pool(key(1));
+
+  assert_eq!(code(pool.set_coauthors(&[key(1), key(2)], &[10000])), error(NewsError::InvalidCoAuthors));
+  assert_eq!(code(pool.set_coauthors(&[key(1), key(1)], &[5000, 5000])), error(NewsError::InvalidCoAuthors));
+  assert_eq!(code(pool.set_coauthors(&[key(1), key(2)], &[6000, 3000])), error(NewsError::InvalidShares));
+  assert_eq!(code(pool.set_coauthors(&[key(1), key(2)], &[10000, 0])), error(NewsError::InvalidShares));
+
+  let authors: Vec<Pubkey> = (1..=MAX_COAUTHOR_COUNT as u8 + 1).map(key).collect();
+  let shares = vec![1; authors.len()];
+  assert_eq!(code(pool.set_coauthors(&authors, &shares)), error(NewsError::InvalidCoAuthors));
+
+  assert_eq!(pool.coauthor_count, 0);
+}
+
+#[test]
+fn set_coauthors_accepts_only_the_reporter() {
+  let mut pool = pool(key(1));
+
+  pool.set_coauthors(&[key(2), key(1)], &[4000, 6000]).unwrap();
+  assert_eq!(pool.coauthor_count, 2);
+  assert_eq!(pool.coauthors[0].accepted, 0);
+  assert_eq!(pool.coauthors[1].accepted, 1);
+
+  pool.accept_coauthorship(key(2)).unwrap();
+  assert_eq!(pool.coauthors[0].accepted, 1);
+  assert_eq!(code(pool.accept_coauthorship(key(3))), error(NewsError::NotCoAuthor));
+
+  // Replacing the list resets earlier acceptances and clears the old entries.
+  pool.set_coauthors(&[key(2)], &[10000]).unwrap();
+  assert_eq!(pool.coauthor_count, 1);
+  assert_eq!(pool.coauthors[0].accepted, 0);
+  assert_eq!(pool.coauthors[1].author, Pubkey::default());
+
+  pool.set_coauthors(&[], &[]).unwrap();
+  assert_eq!(pool.coauthor_count, 0);
+}
+
+#[test]
+fn payout_shares_without_coauthors_go_to_reporter() {
+  let pool = pool(key(1));
+
+  assert_eq!(pool.payout_shares(700), vec![(key(1), 700)]);
+}
+
+#[test]
+fn payout_shares_do_not_overflow_on_large_payouts() {
+  let mut pool = pool(key(1));
+  pool.set_coauthors(&[key(1), key(2)], &[9999, 1]).unwrap();
+
+  let shares = pool.payout_shares(u64::MAX);
+  assert_eq!(shares[0], (key(1), (u64::MAX as u128 * 9999 / 10000) as u64));
+  assert_eq!(shares[0].1 + shares[1].1, u64::MAX);
+}
+
+proptest! {
+  // Each author gets at least their floored share and the payout is split exactly.
+  #[test]
+  fn payout_shares_split_exactly(
+    payout in any::<u64>(),
+    weights in prop::collection::vec(1..1000u32, 1..=MAX_COAUTHOR_COUNT)
+  ) {
+    let total: u32 = weights.iter().sum();
+    let mut shares: Vec<u32> = weights.iter().map(|w| w * TOTAL_SHARE_BPS / total).map(|s| s.max(1)).collect();
+    let assigned: u32 = shares.iter().sum();
+    prop_assume!(assigned <= TOTAL_SHARE_BPS);
+    let last = shares.len() - 1;
+    shares[last] += TOTAL_SHARE_BPS - assigned;
+
+    let authors: Vec<Pubkey> = (1..=shares.len() as u8).map(key).collect();
+    let mut pool = pool(key(1));
+    pool.set_coauthors(&authors, &shares).unwrap();
+
+    let split = pool.payout_shares(payout);
+    prop_assert_eq!(split.len(), authors.len());
+    prop_assert_eq!(split.iter().map(|(_, amount)| *amount as u128).sum::<u128>(), payout as u128);
+    for (i, (author, amount)) in split.iter().enumerate() {
+      let floor = (payout as u128 * shares[i] as u128 / TOTAL_SHARE_BPS as u128) as u64;
+      prop_assert_eq!(*author, authors[i]);
+      prop_assert!(*amount >= floor);
+      if i != last {
+        prop_assert_eq!(*amount, floor);
+      }
+    }
+  }
+}
@@ -0,0 +1,25 @@
+// Shared by every integration test crate; each one uses only some of these.
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_error::ProgramError;
+
+use news::errors::*;
+
+pub fn key(seed: u8) -> Pubkey {
+  Pubkey::new_from_array([seed; 32])
+}
+
+pub fn code<T>(result: Result<T>) -> Option<u32> {
+  match result {
+    Ok(_) => None,
+    Err(e) => match ProgramError::from(e) {
+      ProgramError::Custom(code) => Some(code),
+      other => panic!("unexpected program error {:?}", other),
+    },
+  }
+}
+
+pub fn error(e: NewsError) -> Option<u32> {
+  Some(u32::from(e))
+}
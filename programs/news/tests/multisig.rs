@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use proptest::prelude::*;
+
+use news::account::*;
+use news::constants::*;
+use news::errors::*;
+
+mod common;
+use common::*;
+
+fn multisig(count: u8, threshold: u32) -> Multisig {
+  let signers: Vec<Pubkey> = (1..=count).map(key).collect();
+  let mut multisig = Multisig::default();
+  multisig.set_signers(&signers, threshold).unwrap();
+  multisig
+}
+
+fn proposal(multisig: &Multisig, action: &ProposalAction) -> Proposal {
+  let mut proposal = Proposal { generation: multisig.generation, ..Proposal::default() };
+  proposal.set_action(action).unwrap();
+  proposal
+}
+
+#[test]
+fn set_signers_rejects_invalid_sets() {
+  let mut multisig = Multisig::default();
+
+  assert_eq!(code(multisig.set_signers(&[], 1)), error(NewsError::InvalidMultisig));
+  assert_eq!(code(multisig.set_signers(&[key(1)], 0)), error(NewsError::InvalidMultisig));
+  assert_eq!(code(multisig.set_signers(&[key(1)], 2)), error(NewsError::InvalidMultisig));
+  assert_eq!(code(multisig.set_signers(&[key(1), key(2), key(1)], 2)), error(NewsError::InvalidMultisig));
+
+  let too_many: Vec<Pubkey> = (0..=MAX_MULTISIG_SIGNERS as u8).map(key).collect();
+  assert_eq!(code(multisig.set_signers(&too_many, 1)), error(NewsError::InvalidMultisig));
+
+  assert_eq!(multisig.signer_count, 0);
+  assert_eq!(multisig.generation, 0);
+}
+
+#[test]
+fn set_signers_replaces_set_and_bumps_generation() {
+  let mut multisig = multisig(3, 2);
+  assert_eq!(multisig.generation, 1);
+  assert_eq!(multisig.find_signer(key(3)), Some(2));
+
+  multisig.set_signers(&[key(4)], 1).unwrap();
+
+  assert_eq!(multisig.generation, 2);
+  assert_eq!(multisig.signer_count, 1);
+  assert_eq!(multisig.threshold, 1);
+  assert_eq!(multisig.find_signer(key(4)), Some(0));
+  assert_eq!(multisig.find_signer(key(3)), None);
+  assert_eq!(multisig.signers[1], Pubkey::default());
+}
+
+#[test]
+fn approve_sets_signer_bit_once() {
+  let multisig = multisig(3, 2);
+  let mut proposal = proposal(&multisig, &ProposalAction::WithdrawAll);
+
+  proposal.approve(&multisig, key(3)).unwrap();
+  assert_eq!(proposal.approvals, 0b100);
+
+  assert_eq!(code(proposal.approve(&multisig, key(3))), error(NewsError::AlreadyApproved));
+  assert_eq!(code(proposal.approve(&multisig, key(9))), error(NewsError::NotMultisigSigner));
+
+  proposal.approve(&multisig, key(1)).unwrap();
+  assert_eq!(proposal.approvals, 0b101);
+}
+
+#[test]
+fn execute_requires_threshold_and_runs_once() {
+  let multisig = multisig(3, 2);
+  let action = ProposalAction::Withdraw { amount: 42 };
+  let mut proposal = proposal(&multisig, &action);
+
+  proposal.approve(&multisig, key(1)).unwrap();
+  assert_eq!(code(proposal.execute(&multisig)), error(NewsError::ThresholdNotReached));
+  assert_eq!(proposal.executed, 0);
+
+  proposal.approve(&multisig, key(2)).unwrap();
+  assert_eq!(proposal.execute(&multisig).unwrap(), action);
+  assert_eq!(proposal.executed, 1);
+
+  assert_eq!(code(proposal.execute(&multisig)), error(NewsError::ProposalExecuted));
+  assert_eq!(code(proposal.approve(&multisig, key(3))), error(NewsError::ProposalExecuted));
+}
+
+#[test]
+fn changing_signers_stales_open_proposals() {
+  let mut multisig = multisig(3, 1);
+  let mut proposal = proposal(&multisig, &ProposalAction::WithdrawAll);
+  proposal.approve(&multisig, key(1)).unwrap();
+
+  multisig.set_signers(&[key(1), key(2)], 1).unwrap();
+
+  assert_eq!(code(proposal.approve(&multisig, key(2))), error(NewsError::ProposalStale));
+  assert_eq!(code(proposal.execute(&multisig)), error(NewsError::ProposalStale));
+}
+
+#[test]
+fn oversized_action_is_rejected() {
+  let mut proposal = Proposal::default();
+  let signers = vec![key(1); MAX_ACTION_SIZE / 32 + 1];
+
+  let result = proposal.set_action(&ProposalAction::SetSigners { signers, threshold: 1 });
+  assert_eq!(code(result), error(NewsError::InvalidProposalAction));
+  assert_eq!(proposal.action_len, 0);
+}
+
+proptest! {
+  // Execution succeeds exactly when the number of distinct approving signers reaches the threshold.
+  #[test]
+  fn execute_matches_approval_count(
+    count in 1..=MAX_MULTISIG_SIGNERS as u8,
+    threshold_seed in 0..MAX_MULTISIG_SIGNERS as u32,
+    approvers in prop::collection::vec(1..=MAX_MULTISIG_SIGNERS as u8, 0..20)
+  ) {
+    let threshold = threshold_seed % count as u32 + 1;
+    let multisig = multisig(count, threshold);
+    let mut proposal = proposal(&multisig, &ProposalAction::WithdrawAll);
+
+    let mut approved = 0u32;
+    for approver in approvers.iter() {
+      let result = code(proposal.approve(&multisig, key(*approver)));
+      if *approver > count {
+        prop_assert_eq!(result, error(NewsError::NotMultisigSigner));
+      } else if approved & (1 << (*approver - 1)) != 0 {
+        prop_assert_eq!(result, error(NewsError::AlreadyApproved));
+      } else {
+        prop_assert_eq!(result, None);
+        approved |= 1 << (*approver - 1);
+      }
+      prop_assert_eq!(proposal.approvals, approved);
+    }
+
+    let result = code(proposal.execute(&multisig));
+    if approved.count_ones() >= threshold {
+      prop_assert_eq!(result, None);
+    } else {
+      prop_assert_eq!(result, error(NewsError::ThresholdNotReached));
+    }
+  }
+}
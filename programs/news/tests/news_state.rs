@@ -0,0 +1,57 @@
+use news::account::*;
+use news::errors::*;
+
+mod common;
+use common::*;
+
+const STATES: [NewsState; 6] = [
+  NewsState::Draft,
+  NewsState::Edited,
+  NewsState::Approved,
+  NewsState::Denied,
+  NewsState::Published,
+  NewsState::Retracted,
+];
+
+#[test]
+fn transition_table_allows_only_listed_edges() {
+  use NewsState::*;
+  let allowed = [
+    (Draft, Edited), (Edited, Edited), (Approved, Edited), (Denied, Edited),
+    (Draft, Approved), (Edited, Approved),
+    (Draft, Denied), (Edited, Denied),
+    (Approved, Published),
+    (Published, Retracted),
+  ];
+
+  for from in STATES.iter() {
+    for to in STATES.iter() {
+      let mut pool = Pool { state: *from as u32, ..Pool::default() };
+      let result = code(pool.transition(*to));
+
+      if allowed.contains(&(*from, *to)) {
+        assert_eq!(result, None, "{:?} -> {:?}", from, to);
+        assert_eq!(pool.state, *to as u32);
+      } else {
+        assert_eq!(result, error(NewsError::InvalidStateTransition), "{:?} -> {:?}", from, to);
+        assert_eq!(pool.state, *from as u32);
+      }
+    }
+  }
+}
+
+#[test]
+fn unknown_state_is_rejected() {
+  let mut pool = Pool { state: 6, ..Pool::default() };
+
+  assert_eq!(code(pool.transition(NewsState::Edited)), error(NewsError::InvalidNewsState));
+  assert_eq!(code(Pool { state: u32::MAX, ..Pool::default() }.news_state()), error(NewsError::InvalidNewsState));
+}
+
+#[test]
+fn published_and_retracted_pools_are_not_closable() {
+  for state in STATES.iter() {
+    let closable = !matches!(state, NewsState::Published | NewsState::Retracted);
+    assert_eq!(state.is_closable(), closable, "{:?}", state);
+  }
+}
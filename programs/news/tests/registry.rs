@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use proptest::prelude::*;
+
+use news::account::*;
+use news::constants::*;
+use news::errors::*;
+
+mod common;
+use common::*;
+
+#[derive(Clone, Debug)]
+enum Op {
+  Add(u8, u32),
+  Edit(u8, u8, u32),
+  Delete(u8),
+}
+
+// The reference model: an ordered list of unique (key, role) pairs.
+fn apply(model: &mut Vec<(Pubkey, u32)>, op: &Op) -> Option<u32> {
+  match *op {
+    Op::Add(k, role) => {
+      if model.iter().any(|(r, _)| *r == key(k)) {
+        return error(NewsError::DuplicateReporter);
+      }
+      if model.len() >= MAX_REPORTER_COUNT {
+        return error(NewsError::OverMaxCount);
+      }
+      model.push((key(k), role));
+      None
+    }
+    Op::Edit(old, new, role) => {
+      let index = match model.iter().position(|(r, _)| *r == key(old)) {
+        Some(index) => index,
+        None => return error(NewsError::ReporterNotFound),
+      };
+      if old != new && model.iter().any(|(r, _)| *r == key(new)) {
+        return error(NewsError::DuplicateReporter);
+      }
+      model[index] = (key(new), role);
+      None
+    }
+    Op::Delete(k) => {
+      let index = match model.iter().position(|(r, _)| *r == key(k)) {
+        Some(index) => index,
+        None => return error(NewsError::ReporterNotFound),
+      };
+      model.remove(index);
+      None
+    }
+  }
+}
+
+fn run(user: &mut User, op: &Op) -> Option<u32> {
+  match *op {
+    Op::Add(k, role) => code(user.add_reporter(key(k), role)),
+    Op::Edit(old, new, role) => code(user.edit_reporter(key(old), key(new), role)),
+    Op::Delete(k) => code(user.delete_reporter(key(k))),
+  }
+}
+
+fn assert_matches_model(user: &User, model: &[(Pubkey, u32)]) {
+  assert_eq!(user.count as usize, model.len());
+  for (i, (reporter, role)) in model.iter().enumerate() {
+    assert_eq!(user.reporters[i].reporter, *reporter);
+    assert_eq!(user.reporters[i].role, *role);
+  }
+  for slot in user.reporters[model.len()..].iter() {
+    assert_eq!(slot.reporter, Pubkey::default());
+    assert_eq!(slot.role, 0);
+  }
+}
+
+fn op(keys: u8, adds: u32) -> impl Strategy<Value = Op> {
+  prop_oneof![
+    adds => (0..keys, 1..=ADMIN_ROLE).prop_map(|(k, role)| Op::Add(k, role)),
+    1 => (0..keys, 0..keys, 1..=ADMIN_ROLE).prop_map(|(old, new, role)| Op::Edit(old, new, role)),
+    1 => (0..keys).prop_map(Op::Delete),
+  ]
+}
+
+proptest! {
+  #[test]
+  fn registry_matches_model(ops in prop::collection::vec(op(12, 2), 1..200)) {
+    let mut user = User::default();
+    let mut model = Vec::new();
+
+    for op in ops.iter() {
+      let expected = apply(&mut model, op);
+      let actual = run(&mut user, op);
+      prop_assert_eq!(actual, expected, "op {:?}", op);
+      assert_matches_model(&user, &model);
+    }
+  }
+
+  // A key space wider than the registry drives it into and out of the full state.
+  #[test]
+  fn registry_matches_model_at_capacity(ops in prop::collection::vec(op(MAX_REPORTER_COUNT as u8 + 20, 6), 1..600)) {
+    let mut user = User::default();
+    let mut model = Vec::new();
+
+    for op in ops.iter() {
+      let expected = apply(&mut model, op);
+      let actual = run(&mut user, op);
+      prop_assert_eq!(actual, expected, "op {:?}", op);
+      assert_matches_model(&user, &model);
+    }
+  }
+
+  #[test]
+  fn find_reporter_returns_stored_role(role in 1..=ADMIN_ROLE) {
+    let mut user = User::default();
+    user.add_reporter(key(1), role).unwrap();
+
+    let index = user.find_reporter(key(1));
+    prop_assert_eq!(index, Some(0));
+    prop_assert_eq!(user.reporters[0].role, role);
+    prop_assert_eq!(user.find_reporter(key(2)), None);
+  }
+}
+
+#[test]
+fn full_registry_rejects_add_without_overwriting() {
+  let mut user = User::default();
+  for i in 0..MAX_REPORTER_COUNT {
+    user.add_reporter(key(i as u8), SENIOR_ROLE).unwrap();
+  }
+
+  assert_eq!(code(user.add_reporter(key(200), ADMIN_ROLE)), error(NewsError::OverMaxCount));
+  assert_eq!(user.count as usize, MAX_REPORTER_COUNT);
+  assert_eq!(user.reporters[MAX_REPORTER_COUNT - 1].reporter, key(MAX_REPORTER_COUNT as u8 - 1));
+}
+
+#[test]
+fn delete_missing_reporter_fails() {
+  let mut user = User::default();
+  user.add_reporter(key(1), SENIOR_ROLE).unwrap();
+
+  assert_eq!(code(user.delete_reporter(key(2))), error(NewsError::ReporterNotFound));
+  assert_eq!(user.count, 1);
+}